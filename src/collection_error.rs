@@ -0,0 +1,27 @@
+use core::fmt::{Debug, Display};
+
+/// Inconsistencies in the structure of a self referential collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CollectionError {
+    /// LenMismatch => Length of the collection is different than the number of active nodes in its storage.
+    LenMismatch {
+        /// Length of the collection.
+        len: usize,
+        /// Number of active nodes in the storage.
+        num_active_nodes: usize,
+    },
+    /// DanglingReference => The active node at the `position` of the storage references a node which is closed
+    /// or which does not belong to the collection.
+    DanglingReference {
+        /// Position of the referencing node in the storage.
+        position: usize,
+    },
+    /// DanglingEnd => Ends of the collection reference a node which is closed or which does not belong to the collection.
+    DanglingEnd,
+}
+
+impl Display for CollectionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <CollectionError as Debug>::fmt(self, f)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{MemoryPolicy, Node, NodeRefs, SelfRefCol, Variant};
+use orx_pinned_vec::PinnedVec;
+
+/// Clones the collection into a new storage with the same layout, data and structure, where the
+/// references point to the nodes of the new collection.
+///
+/// `clone_from` reuses the allocation of the destination as much as the `clear` method of its storage
+/// allows, which avoids reallocating when cloning repeatedly, and advances its memory state.
+impl<V, M, P> Clone for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    V::Item: Clone,
+    V::Prev: NodeRefs<V>,
+    V::Next: NodeRefs<V>,
+    V::Ends: NodeRefs<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>> + Default,
+{
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        self.clone_structure_into(&mut clone);
+        *clone.memory_mut() = self.memory().clone();
+        clone
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        source.clone_structure_into(self);
+        *self.memory_mut() = source.memory().clone();
+    }
+}
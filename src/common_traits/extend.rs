@@ -0,0 +1,17 @@
+use crate::{MemoryPolicy, Node, SelfRefCol, Variant};
+use orx_pinned_vec::PinnedVec;
+
+/// Pushes each item as a free node; i.e., no links are established among the new nodes
+/// or between the new nodes and the existing ones.
+impl<V, M, P> Extend<V::Item> for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn extend<I: IntoIterator<Item = V::Item>>(&mut self, iter: I) {
+        for data in iter.into_iter() {
+            self.push(data);
+        }
+    }
+}
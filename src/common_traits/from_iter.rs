@@ -1,6 +1,8 @@
 use crate::{MemoryPolicy, Node, SelfRefCol, Variant};
 use orx_pinned_vec::PinnedVec;
 
+/// Creates the collection where each item is pushed as a free node; i.e., no links are established
+/// among the nodes.
 impl<V, M, P> FromIterator<V::Item> for SelfRefCol<V, M, P>
 where
     V: Variant,
@@ -0,0 +1,41 @@
+use crate::{MemoryPolicy, Node, NodeIdx, SelfRefCol, Variant};
+use core::ops::{Index, IndexMut};
+use orx_pinned_vec::PinnedVec;
+
+/// Returns a reference to the data of the node with the given `NodeIdx`.
+///
+/// # Panics
+///
+/// Panics with the corresponding `NodeIdxError` if the index is invalid or the node is removed.
+/// Prefer `try_get_ptr` or `node_from_idx` for fallible access.
+impl<V, M, P> Index<&NodeIdx<V>> for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    type Output = V::Item;
+
+    fn index(&self, index: &NodeIdx<V>) -> &Self::Output {
+        let ptr = self.try_get_ptr(index).expect("invalid node index");
+        unsafe { self.data_unchecked(&ptr) }
+    }
+}
+
+/// Returns a mutable reference to the data of the node with the given `NodeIdx`.
+///
+/// # Panics
+///
+/// Panics with the corresponding `NodeIdxError` if the index is invalid or the node is removed.
+/// Prefer `try_get_ptr` or `try_node_mut_from_idx` for fallible access.
+impl<V, M, P> IndexMut<&NodeIdx<V>> for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn index_mut(&mut self, index: &NodeIdx<V>) -> &mut Self::Output {
+        let ptr = self.try_get_ptr(index).expect("invalid node index");
+        unsafe { self.data_mut_unchecked(&ptr) }
+    }
+}
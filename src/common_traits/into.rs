@@ -3,6 +3,8 @@ use crate::{
 };
 use orx_pinned_vec::PinnedVec;
 
+/// Converts the collection to one with the threshold policy; the memory state is not changed,
+/// and hence, all node indices remain valid until the next reclaim moves the nodes.
 impl<const D: usize, R, V, P> From<SelfRefCol<V, MemoryReclaimNever, P>>
     for SelfRefCol<V, MemoryReclaimOnThreshold<D, V, R>, P>
 where
@@ -16,6 +18,9 @@ where
     }
 }
 
+/// Converts the collection to one with the never-reclaim policy; the memory state is not changed,
+/// and hence, node indices which are valid before the conversion remain valid henceforth unless
+/// closed nodes are reclaimed manually.
 impl<const D: usize, R, V, P> From<SelfRefCol<V, MemoryReclaimOnThreshold<D, V, R>, P>>
     for SelfRefCol<V, MemoryReclaimNever, P>
 where
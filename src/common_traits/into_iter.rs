@@ -0,0 +1,52 @@
+use crate::{MemoryPolicy, Node, SelfRefCol, Variant};
+use core::{iter::FusedIterator, marker::PhantomData};
+use orx_pinned_vec::PinnedVec;
+
+/// Iterator over references to the data of the active nodes of a self referential collection,
+/// in the order of their positions in the underlying storage.
+pub struct ActiveDataIter<'a, V: Variant, P: PinnedVec<Node<V>>> {
+    nodes: &'a P,
+    pos: usize,
+    phantom: PhantomData<&'a V>,
+}
+
+impl<'a, V: Variant + 'a, P: PinnedVec<Node<V>>> Iterator for ActiveDataIter<'a, V, P> {
+    type Item = &'a V::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.nodes.get(self.pos) {
+            self.pos += 1;
+            if let Some(data) = node.data() {
+                return Some(data);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.nodes.len().saturating_sub(self.pos)))
+    }
+}
+
+impl<'a, V: Variant + 'a, P: PinnedVec<Node<V>>> FusedIterator for ActiveDataIter<'a, V, P> {}
+
+/// Iterates over references to the data of the active nodes in the order of their positions
+/// in the underlying storage; closed nodes are skipped.
+impl<'a, V, M, P> IntoIterator for &'a SelfRefCol<V, M, P>
+where
+    V: Variant + 'a,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    type Item = &'a V::Item;
+
+    type IntoIter = ActiveDataIter<'a, V, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ActiveDataIter {
+            nodes: self.nodes(),
+            pos: 0,
+            phantom: PhantomData,
+        }
+    }
+}
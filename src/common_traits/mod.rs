@@ -1,3 +1,9 @@
+mod clone;
+mod extend;
 mod from;
 mod from_iter;
+mod index;
 mod into;
+mod into_iter;
+
+pub use into_iter::ActiveDataIter;
@@ -1,5 +1,9 @@
-use crate::{node::Node, NodePtr, Refs, Utilization, Variant};
-use orx_pinned_vec::PinnedVec;
+use crate::{
+    node::Node, CollectionError, MoveError, NodePtr, NodeRefs, Refs, RefsArrayLeftMost, RefsSingle,
+    Stats, Utilization, Variant,
+};
+use alloc::{collections::VecDeque, vec::Vec};
+use orx_pinned_vec::{CapacityState, PinnedVec};
 use orx_split_vec::{Recursive, SplitVec};
 
 /// Core collection of the self referential collection.
@@ -11,6 +15,8 @@ where
     nodes: P,
     ends: V::Ends,
     len: usize,
+    stats: Stats,
+    num_moves: usize,
 }
 
 impl<V, P> Default for CoreCol<V, P>
@@ -23,6 +29,26 @@ where
     }
 }
 
+impl<V, P> AsRef<CoreCol<V, P>> for CoreCol<V, P>
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    fn as_ref(&self) -> &CoreCol<V, P> {
+        self
+    }
+}
+
+impl<V, P> AsMut<CoreCol<V, P>> for CoreCol<V, P>
+where
+    V: Variant,
+    P: PinnedVec<Node<V>>,
+{
+    fn as_mut(&mut self) -> &mut CoreCol<V, P> {
+        self
+    }
+}
+
 impl<V, P> CoreCol<V, P>
 where
     V: Variant,
@@ -37,11 +63,108 @@ where
             nodes: P::default(),
             ends: Refs::empty(),
             len: 0,
+            stats: Stats::default(),
+            num_moves: 0,
         }
     }
 
+    /// Creates a new collection with the same layout and the same references as this collection,
+    /// where the data of each active node is computed by applying `f` on the data of the corresponding node.
+    ///
+    /// Each node of the new collection is at the same position as the node it is cloned from; and hence,
+    /// a reference to the node at position `p` is replaced by a reference to the node at position `p`
+    /// of the new collection.
+    pub fn clone_with_data<F>(&self, f: F) -> Self
+    where
+        P: Default,
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+        F: FnMut(&V::Item) -> V::Item,
+    {
+        let mut nodes = P::default();
+        let ends = self.clone_nodes_into(&mut nodes, f);
+        Self::from_raw_parts(nodes, ends, self.len)
+    }
+
+    /// Clears the `dst` collection and clones the layout, the references and the data of this collection
+    /// into it, where the data of each active node is computed by applying `f`.
+    pub(crate) fn clone_into_with<F>(&self, dst: &mut Self, f: F)
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+        F: FnMut(&V::Item) -> V::Item,
+    {
+        dst.clear_core();
+        dst.ends = self.clone_nodes_into(&mut dst.nodes, f);
+        dst.len = self.len;
+        dst.stats.peak_storage_len = dst.stats.peak_storage_len.max(dst.nodes.len());
+    }
+
+    /// Pushes clones of the nodes of this collection to the empty `nodes` storage, where the data of each
+    /// active node is computed by applying `f`, and the references are replaced by references to the
+    /// nodes at the same positions of `nodes`; returns the ends referencing `nodes`.
+    ///
+    /// References pointing outside of this collection's storage cannot be mapped into `nodes` and are
+    /// removed, so that the clone never refers to the memory of another collection.
+    fn clone_nodes_into<F>(&self, nodes: &mut P, mut f: F) -> V::Ends
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+        F: FnMut(&V::Item) -> V::Item,
+    {
+        debug_assert!(nodes.is_empty());
+        for node in self.nodes.iter() {
+            let data = node.data().map(&mut f);
+            nodes.push(Node::from_parts(
+                data,
+                node.prev().clone(),
+                node.next().clone(),
+            ));
+        }
+
+        fn remap<V, P, R>(src: &CoreCol<V, P>, nodes: &P, refs: &mut R)
+        where
+            V: Variant,
+            P: PinnedVec<Node<V>>,
+            R: NodeRefs<V>,
+        {
+            refs.retain_ptrs(|x| src.position_of(x).is_some_and(|p| p < nodes.len()));
+            for ptr in refs.iter_ptr_mut() {
+                if let Some(ptr_in_new) = src.position_of(ptr).and_then(|p| nodes.get_ptr(p)) {
+                    *ptr = NodePtr::new(ptr_in_new);
+                }
+            }
+        }
+
+        for pos in 0..nodes.len() {
+            let mut prev = nodes[pos].prev().clone();
+            remap(self, nodes, &mut prev);
+            let mut next = nodes[pos].next().clone();
+            remap(self, nodes, &mut next);
+            *nodes[pos].prev_mut() = prev;
+            *nodes[pos].next_mut() = next;
+        }
+
+        let mut ends = self.ends.clone();
+        remap(self, nodes, &mut ends);
+        ends
+    }
+
     pub(crate) fn from_raw_parts(nodes: P, ends: V::Ends, len: usize) -> Self {
-        Self { nodes, ends, len }
+        let stats = Stats {
+            peak_storage_len: nodes.len(),
+            ..Default::default()
+        };
+        Self {
+            nodes,
+            ends,
+            len,
+            stats,
+            num_moves: 0,
+        }
     }
 
     /// Destructs the collection into its inner pinned vec, ends and length.
@@ -49,12 +172,30 @@ where
         (self.nodes, self.ends, self.len)
     }
 
+    /// Returns references to the inner pinned vec and ends, together with the length, without
+    /// consuming the collection.
+    pub fn as_raw_parts(&self) -> (&P, &V::Ends, usize) {
+        (&self.nodes, &self.ends, self.len)
+    }
+
+    /// Consumes the collection and returns an iterator yielding the data of the active nodes
+    /// in the order of the underlying storage, skipping the closed nodes.
+    pub fn into_active_data(self) -> impl Iterator<Item = V::Item> {
+        self.nodes.into_iter().filter_map(|x| x.into_data())
+    }
+
     pub(crate) fn with_active_nodes(nodes: P) -> Self {
         debug_assert!(nodes.iter().all(|x| x.data().is_some()));
+        let stats = Stats {
+            peak_storage_len: nodes.len(),
+            ..Default::default()
+        };
         Self {
             len: nodes.len(),
             nodes,
             ends: Refs::empty(),
+            stats,
+            num_moves: 0,
         }
     }
 
@@ -69,6 +210,36 @@ where
         }
     }
 
+    /// Returns an estimate of the number of bytes used by the collection.
+    ///
+    /// This is the capacity of the nodes storage times the size of a node, plus the heap memory
+    /// held by the references of all nodes, such as the capacities of `RefsVec` references.
+    pub fn memory_footprint(&self) -> usize
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+    {
+        let nodes = self.nodes.capacity() * core::mem::size_of::<Node<V>>();
+        nodes + self.refs_heap_bytes()
+    }
+
+    /// Returns the number of bytes allocated on the heap by the references of all nodes.
+    pub(crate) fn refs_heap_bytes(&self) -> usize
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+    {
+        self.nodes
+            .iter()
+            .map(|x| x.prev().heap_bytes() + x.next().heap_bytes())
+            .sum()
+    }
+
+    #[inline(always)]
+    pub(crate) fn stats(&self) -> Stats {
+        self.stats
+    }
+
     /// Returns length of the self referential collection.
     #[inline(always)]
     pub fn len(&self) -> usize {
@@ -80,18 +251,426 @@ where
         self.len == 0
     }
 
+    /// Returns the number of active nodes whose data satisfies the given predicate `f`.
+    pub fn count_active_where<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&V::Item) -> bool,
+    {
+        self.nodes
+            .iter()
+            .filter_map(|x| x.data())
+            .filter(|x| f(x))
+            .count()
+    }
+
+    /// Folds the data of the active nodes, in the order of their positions in the underlying storage,
+    /// into an accumulator starting from `init` by applying `f`.
+    pub fn fold_active<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &V::Item) -> B,
+    {
+        self.nodes.iter().filter_map(|x| x.data()).fold(init, f)
+    }
+
+    /// Returns an iterator of the positions of the active nodes in the underlying nodes storage.
+    pub fn active_positions(&self) -> impl Iterator<Item = usize> + '_ {
+        self.nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.is_active())
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the out-degree histogram of the active nodes, where the element at index `d`
+    /// is the number of active nodes with exactly `d` next references.
+    ///
+    /// The histogram is empty if there exists no active node.
+    pub fn out_degree_histogram(&self) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        for node in self.nodes.iter().filter(|x| x.is_active()) {
+            let degree = node.next().len();
+            if degree >= histogram.len() {
+                histogram.resize(degree + 1, 0);
+            }
+            histogram[degree] += 1;
+        }
+        histogram
+    }
+
     /// Returns a reference to the underlying nodes storage.
     #[inline(always)]
     pub fn nodes(&self) -> &P {
         &self.nodes
     }
 
+    /// Returns a reference to the node at the given `pos` of the underlying nodes storage;
+    /// None if the position is out of bounds.
+    #[inline(always)]
+    pub fn get_node(&self, pos: usize) -> Option<&Node<V>> {
+        self.nodes.get(pos)
+    }
+
+    /// Returns a reference to the node referenced by the `which`-th slot of the ends; None if the slot
+    /// is empty or out of bounds.
+    ///
+    /// For instance, slot 0 is the front of a list or the root of a tree, and slot 1 is the back of a
+    /// doubly linked list whose ends is a `RefsArray`.
+    pub fn end_node(&self, which: usize) -> Option<&Node<V>>
+    where
+        V::Ends: NodeRefs<V>,
+    {
+        self.ends.ptr_at(which).map(|x| self.node(x))
+    }
+
     /// Returns a reference to the node with the given `node_ptr`.
+    ///
+    /// In debug builds, asserts that the `node_ptr` belongs to this collection.
     #[inline(always)]
     pub fn node(&self, node_ptr: &NodePtr<V>) -> &Node<V> {
+        debug_assert!(
+            self.position_of(node_ptr).is_some(),
+            "Pointer does not belong to the collection"
+        );
         unsafe { &*node_ptr.ptr() }
     }
 
+    /// Returns the pointers of the nodes referenced by the next references of the node with the given `node_ptr`.
+    pub fn next_ptrs_of(&self, node_ptr: &NodePtr<V>) -> Vec<NodePtr<V>>
+    where
+        V::Next: NodeRefs<V>,
+    {
+        self.node(node_ptr).next().iter_ptr().cloned().collect()
+    }
+
+    /// Returns the pointers of the nodes referenced by the previous references of the node with the given `node_ptr`.
+    pub fn prev_ptrs_of(&self, node_ptr: &NodePtr<V>) -> Vec<NodePtr<V>>
+    where
+        V::Prev: NodeRefs<V>,
+    {
+        self.node(node_ptr).prev().iter_ptr().cloned().collect()
+    }
+
+    /// Returns the pointers of the active nodes without any next references; such as the leaves of a tree.
+    pub fn leaves(&self) -> Vec<NodePtr<V>> {
+        self.active_positions()
+            .filter(|&pos| self.nodes[pos].next().is_empty())
+            .map(|pos| self.node_ptr_at_pos(pos))
+            .collect()
+    }
+
+    /// Returns the pointers of the active nodes without any previous references; such as the roots of a tree.
+    pub fn roots(&self) -> Vec<NodePtr<V>> {
+        self.active_positions()
+            .filter(|&pos| self.nodes[pos].prev().is_empty())
+            .map(|pos| self.node_ptr_at_pos(pos))
+            .collect()
+    }
+
+    /// Returns the number of next references of the active nodes pointing to the node with the given `ptr`;
+    /// a reference is counted as many times as it appears.
+    ///
+    /// Unlike the previous references of the node, which may not be maintained by some structures,
+    /// the in-degree is computed by scanning all active nodes.
+    pub fn in_degree(&self, ptr: &NodePtr<V>) -> usize
+    where
+        V::Next: NodeRefs<V>,
+    {
+        self.nodes
+            .iter()
+            .filter(|node| node.is_active())
+            .map(|node| node.next().iter_ptr().filter(|x| *x == ptr).count())
+            .sum()
+    }
+
+    /// Returns the depth of the node with the given `ptr` in a tree, which is the number of hops following
+    /// the previous (parent) references until reaching a node without a previous reference; such as the root.
+    ///
+    /// Returns None if the pointer does not belong to an active node of this collection, if a parent reference
+    /// is invalid, or if the parent references contain a cycle.
+    pub fn depth_of(&self, ptr: &NodePtr<V>) -> Option<usize>
+    where
+        V: Variant<Prev = RefsSingle<V>>,
+    {
+        let mut pos = self.position_of(ptr)?;
+        let mut depth = 0;
+        loop {
+            let node = &self.nodes[pos];
+            if !node.is_active() || depth > self.len {
+                return None;
+            }
+            match node.prev().get() {
+                Some(parent) => pos = self.position_of(&parent)?,
+                None => return Some(depth),
+            }
+            depth += 1;
+        }
+    }
+
+    /// Returns the pairs of the data of the source and target nodes of all next references among the active
+    /// nodes, in the order of the positions of the source nodes in the underlying storage.
+    ///
+    /// References to closed nodes or to nodes not belonging to this collection are skipped.
+    pub fn edges_as_data_pairs(&self) -> Vec<(&V::Item, &V::Item)>
+    where
+        V::Next: NodeRefs<V>,
+    {
+        let mut pairs = Vec::new();
+        for node in self.nodes.iter() {
+            let Some(source) = node.data() else {
+                continue;
+            };
+            for ptr in node.next().iter_ptr() {
+                let target = self.position_of(ptr).and_then(|pos| self.nodes[pos].data());
+                if let Some(target) = target {
+                    pairs.push((source, target));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// Returns the shortest path from the node with the given `from` pointer to the node with the `to`
+    /// pointer following the next references, including both ends; None if `to` is not reachable from `from`.
+    ///
+    /// The path is found by a breadth-first search.
+    pub fn path_between(&self, from: &NodePtr<V>, to: &NodePtr<V>) -> Option<Vec<NodePtr<V>>>
+    where
+        V::Next: NodeRefs<V>,
+    {
+        let from_pos = self.position_of(from)?;
+        let to_pos = self.position_of(to)?;
+
+        let mut parents: Vec<Option<usize>> = alloc::vec![None; self.nodes.len()];
+        parents[from_pos] = Some(from_pos);
+        let mut queue = VecDeque::from([from_pos]);
+
+        while let Some(pos) = queue.pop_front() {
+            if pos == to_pos {
+                let mut path = alloc::vec![self.node_ptr_at_pos(pos)];
+                let mut current = pos;
+                while let Some(parent) = parents[current].filter(|&x| x != current) {
+                    path.push(self.node_ptr_at_pos(parent));
+                    current = parent;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            for next in self.next_ptrs_of(&self.node_ptr_at_pos(pos)) {
+                if let Some(next_pos) = self.position_of(&next) {
+                    if parents[next_pos].is_none() {
+                        parents[next_pos] = Some(pos);
+                        queue.push_back(next_pos);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the active nodes in a topological order with respect to the next references, such that
+    /// every node appears before the nodes it references; the order is computed by Kahn's algorithm.
+    ///
+    /// If the next references contain a cycle, returns the error containing the active nodes which could
+    /// not be ordered; i.e., the nodes on a cycle or reachable from a cycle.
+    pub fn topo_order(&self) -> Result<Vec<NodePtr<V>>, Vec<NodePtr<V>>>
+    where
+        V::Next: NodeRefs<V>,
+    {
+        let mut in_degrees = alloc::vec![0usize; self.nodes.len()];
+        for node in self.nodes.iter().filter(|x| x.is_active()) {
+            for pos in node.next().iter_ptr().filter_map(|x| self.position_of(x)) {
+                in_degrees[pos] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<_> = self
+            .active_positions()
+            .filter(|&pos| in_degrees[pos] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len);
+
+        while let Some(pos) = queue.pop_front() {
+            let ptr = self.node_ptr_at_pos(pos);
+            for next_pos in self
+                .node(&ptr)
+                .next()
+                .iter_ptr()
+                .filter_map(|x| self.position_of(x))
+            {
+                in_degrees[next_pos] -= 1;
+                if in_degrees[next_pos] == 0 && self.nodes[next_pos].is_active() {
+                    queue.push_back(next_pos);
+                }
+            }
+            order.push(ptr);
+        }
+
+        match order.len() == self.len {
+            true => Ok(order),
+            false => Err(self
+                .active_positions()
+                .filter(|&pos| in_degrees[pos] > 0)
+                .map(|pos| self.node_ptr_at_pos(pos))
+                .collect()),
+        }
+    }
+
+    /// Returns an iterator over the pointers of the nodes reachable from the node with the given `start`
+    /// by following the next references, in depth first pre-order.
+    ///
+    /// Each node is visited at most once; hence, the traversal terminates even if the references contain
+    /// cycles. References to closed nodes or to nodes not belonging to this collection are skipped, and
+    /// the iterator is empty if the `start` node is not an active node of this collection.
+    pub fn dfs_from(&self, start: &NodePtr<V>) -> impl Iterator<Item = NodePtr<V>> + '_
+    where
+        V::Next: NodeRefs<V>,
+    {
+        let mut visited = alloc::vec![false; self.nodes.len()];
+        let mut stack: Vec<_> = self.position_of(start).into_iter().collect();
+        core::iter::from_fn(move || loop {
+            let pos = stack.pop()?;
+            let node = &self.nodes[pos];
+            if visited[pos] || !node.is_active() {
+                continue;
+            }
+            visited[pos] = true;
+            let children = node.next().iter_ptr().filter_map(|x| self.position_of(x));
+            let num_children = stack.len();
+            stack.extend(children);
+            stack[num_children..].reverse();
+            return Some(self.node_ptr_at_pos(pos));
+        })
+    }
+
+    /// Returns true if the next references of the active nodes do not contain a cycle; i.e., if they
+    /// form a directed acyclic graph, such as a list or a tree.
+    ///
+    /// The check is a depth first search from all active nodes, which fails on the first back edge.
+    pub fn is_acyclic_next(&self) -> bool
+    where
+        V::Next: NodeRefs<V>,
+    {
+        const UNVISITED: u8 = 0;
+        const ON_STACK: u8 = 1;
+        const DONE: u8 = 2;
+
+        let next_positions = |pos: usize| -> Vec<usize> {
+            self.nodes[pos]
+                .next()
+                .iter_ptr()
+                .filter_map(|x| self.position_of(x))
+                .filter(|&x| self.nodes[x].is_active())
+                .collect()
+        };
+
+        let mut states = alloc::vec![UNVISITED; self.nodes.len()];
+        let mut stack = Vec::new();
+
+        for root in self.active_positions() {
+            if states[root] != UNVISITED {
+                continue;
+            }
+            states[root] = ON_STACK;
+            stack.push((root, next_positions(root)));
+
+            while let Some((pos, children)) = stack.last_mut() {
+                match children.pop() {
+                    Some(child) => match states[child] {
+                        ON_STACK => return false,
+                        UNVISITED => {
+                            states[child] = ON_STACK;
+                            stack.push((child, next_positions(child)));
+                        }
+                        _ => {}
+                    },
+                    None => {
+                        states[*pos] = DONE;
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns the number of connected components of the active nodes, where both the previous and
+    /// the next references are treated as undirected edges.
+    ///
+    /// For instance, the result is the number of separate lists or trees kept in the collection.
+    pub fn num_connected_components(&self) -> usize
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+    {
+        fn root(parents: &mut [usize], mut pos: usize) -> usize {
+            while parents[pos] != pos {
+                parents[pos] = parents[parents[pos]];
+                pos = parents[pos];
+            }
+            pos
+        }
+
+        let mut parents: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut num_components = self.len;
+
+        for pos in self.active_positions() {
+            let node = &self.nodes[pos];
+            let neighbors = node.prev().iter_ptr().chain(node.next().iter_ptr());
+            for other in neighbors.filter_map(|x| self.position_of(x)) {
+                if self.nodes[other].is_closed() {
+                    continue;
+                }
+                let (a, b) = (root(&mut parents, pos), root(&mut parents, other));
+                if a != b {
+                    parents[a] = b;
+                    num_components -= 1;
+                }
+            }
+        }
+
+        num_components
+    }
+
+    /// Checks the consistency of the collection and returns the first detected problem, if any:
+    /// * [`CollectionError::LenMismatch`] if the length differs from the number of active nodes,
+    /// * [`CollectionError::DanglingReference`] if an active node references a closed or foreign node,
+    /// * [`CollectionError::DanglingEnd`] if the ends reference a closed or foreign node.
+    pub fn check(&self) -> Result<(), CollectionError>
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        let is_valid = |ptr: &NodePtr<V>| {
+            self.position_of(ptr)
+                .is_some_and(|pos| self.nodes[pos].is_active())
+        };
+
+        let num_active_nodes = self.nodes.iter().filter(|x| x.is_active()).count();
+        if num_active_nodes != self.len {
+            return Err(CollectionError::LenMismatch {
+                len: self.len,
+                num_active_nodes,
+            });
+        }
+
+        for position in self.active_positions() {
+            let node = &self.nodes[position];
+            let mut refs = node.prev().iter_ptr().chain(node.next().iter_ptr());
+            if !refs.all(is_valid) {
+                return Err(CollectionError::DanglingReference { position });
+            }
+        }
+
+        match self.ends.iter_ptr().all(is_valid) {
+            true => Ok(()),
+            false => Err(CollectionError::DanglingEnd),
+        }
+    }
+
     /// Returns the position of the node with the given `node_ptr`,
     /// None if the pointer is not valid.
     #[inline(always)]
@@ -132,6 +711,13 @@ where
         &self.ends
     }
 
+    /// Returns the number of ends that are currently present; such as up to two for a doubly linked list
+    /// tracking its front and back, or up to one for a singly linked list tracking its front.
+    #[inline(always)]
+    pub fn num_ends(&self) -> usize {
+        self.ends.len()
+    }
+
     /// Returns the pointer of the element with the given `node_position`
     /// in the underlying nodes storage.
     ///
@@ -146,6 +732,24 @@ where
 
     // mut
 
+    #[inline(always)]
+    pub(crate) fn stats_mut(&mut self) -> &mut Stats {
+        &mut self.stats
+    }
+
+    /// Returns the number of nodes moved so far, including the moves made outside of reclaiming
+    /// which are not reflected in the statistics.
+    #[inline(always)]
+    pub(crate) fn num_moves(&self) -> usize {
+        self.num_moves
+    }
+
+    /// Counts `num_moves` nodes relocated by means other than `move_node`.
+    #[inline(always)]
+    pub(crate) fn count_moves(&mut self, num_moves: usize) {
+        self.num_moves += num_moves;
+    }
+
     pub(crate) fn clear_core(&mut self) {
         self.len = 0;
         self.ends.clear();
@@ -158,13 +762,65 @@ where
         &mut self.nodes
     }
 
+    /// Returns a mutable reference to the node at the given `pos` of the underlying nodes storage;
+    /// None if the position is out of bounds.
+    #[inline(always)]
+    pub fn get_node_mut(&mut self, pos: usize) -> Option<&mut Node<V>> {
+        self.nodes.get_mut(pos)
+    }
+
+    /// Calls `f` with the pointer and a mutable reference to the data of each active node,
+    /// in the order of their positions in the underlying storage.
+    ///
+    /// Since the collection is mutably borrowed during the traversal, `f` cannot access the other nodes;
+    /// the pointers can be stored and used to update the links after the traversal is completed.
+    pub fn for_each_active_data_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(NodePtr<V>, &mut V::Item),
+    {
+        for pos in 0..self.nodes.len() {
+            let ptr = self.node_ptr_at_pos(pos);
+            if let Some(data) = self.nodes.get_mut(pos).and_then(|x| x.data_mut()) {
+                f(ptr, data);
+            }
+        }
+    }
+
     /// Pushes the element with the given `data` and returns its pointer.
     pub fn push(&mut self, data: V::Item) -> NodePtr<V> {
         self.len += 1;
+        let capacity = self.nodes.capacity();
         let ptr = self.nodes.push_get_ptr(Node::new_free_node(data));
+        self.track_growth(capacity);
         NodePtr::new(ptr as *mut Node<V>)
     }
 
+    /// Pushes the element with the given `data` and returns its pointer;
+    /// returns back `Err(data)` if the underlying pinned vec is full and cannot grow,
+    /// such as a `FixedVec` at its capacity, rather than panicking.
+    ///
+    /// Note that allocation failures of growable storages are not intercepted.
+    pub fn try_push(&mut self, data: V::Item) -> Result<NodePtr<V>, V::Item> {
+        let len = self.nodes.len();
+        let cannot_grow = match self.nodes.capacity_state() {
+            CapacityState::FixedCapacity(capacity) => len >= capacity,
+            CapacityState::DynamicCapacity { .. } => len >= self.nodes.capacity_bound(),
+        };
+        match cannot_grow {
+            true => Err(data),
+            false => Ok(self.push(data)),
+        }
+    }
+
+    fn track_growth(&mut self, prior_capacity: usize) {
+        if self.nodes.capacity() > prior_capacity {
+            self.stats.num_growths += 1;
+        }
+        if self.nodes.len() > self.stats.peak_storage_len {
+            self.stats.peak_storage_len = self.nodes.len();
+        }
+    }
+
     /// Returns a mutable reference to the data.
     ///
     /// # Panics
@@ -189,8 +845,9 @@ where
     /// Panics if the node was already closed.
     #[inline(always)]
     pub fn close(&mut self, node_ptr: &NodePtr<V>) -> V::Item {
+        let data = unsafe { &mut *node_ptr.ptr() }.close();
         self.len -= 1;
-        unsafe { &mut *node_ptr.ptr() }.close()
+        data
     }
 
     /// Returns a mutable reference to the ends of the collection.
@@ -199,8 +856,14 @@ where
     }
 
     /// Returns a mutable reference to the node with the given `node_ptr`.
+    ///
+    /// In debug builds, asserts that the `node_ptr` belongs to this collection.
     #[inline(always)]
     pub fn node_mut(&mut self, node_ptr: &NodePtr<V>) -> &mut Node<V> {
+        debug_assert!(
+            self.position_of(node_ptr).is_some(),
+            "Pointer does not belong to the collection"
+        );
         unsafe { &mut *node_ptr.ptr() }
     }
 
@@ -212,6 +875,239 @@ where
         debug_assert!(self.nodes[active_position].is_active());
 
         self.nodes_mut().swap(active_position, closed_position);
+        self.num_moves += 1;
+    }
+
+    /// Swaps the closed node at the `closed_position` with the active node at the `active_position`,
+    /// as `move_node` does, after validating its preconditions; returns the error without changing the
+    /// collection if either of the positions is out of bounds, if `closed_position` is not less than
+    /// `active_position`, if the source node is closed or if the destination node is active.
+    pub fn try_move_node(
+        &mut self,
+        closed_position: usize,
+        active_position: usize,
+    ) -> Result<(), MoveError> {
+        let len = self.nodes.len();
+        for position in [closed_position, active_position] {
+            if position >= len {
+                return Err(MoveError::OutOfBounds { position, len });
+            }
+        }
+        if closed_position >= active_position {
+            return Err(MoveError::NotForward {
+                closed: closed_position,
+                active: active_position,
+            });
+        }
+        if self.nodes[active_position].is_closed() {
+            return Err(MoveError::SourceClosed);
+        }
+        if self.nodes[closed_position].is_active() {
+            return Err(MoveError::DestinationActive);
+        }
+
+        self.move_node(closed_position, active_position);
+        Ok(())
+    }
+
+    /// Removes the active node at the given storage position `pos` in constant time by moving
+    /// the last active node of the storage into `pos`, and truncates the trailing positions.
+    ///
+    /// Returns the data of the removed node together with the previous position of the node which
+    /// is moved into `pos`; None if no node is moved.
+    ///
+    /// Since the moved node is relocated, the caller is responsible for updating the references
+    /// to the moved node, which can be obtained by `node_ptr_at_pos(pos)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds or if the node at `pos` is already closed.
+    pub(crate) fn swap_remove_active(&mut self, pos: usize) -> (V::Item, Option<usize>) {
+        let ptr = self.node_ptr_at_pos(pos);
+        let data = self.close(&ptr);
+
+        let last_active = self.last_active_after(pos);
+        match last_active {
+            Some(last) => {
+                self.move_node(pos, last);
+                self.nodes.truncate(last);
+            }
+            None => self.nodes.truncate(pos),
+        }
+
+        (data, last_active)
+    }
+
+    fn last_active_after(&self, pos: usize) -> Option<usize> {
+        ((pos + 1)..self.nodes.len())
+            .rev()
+            .find(|&i| self.nodes[i].is_active())
+    }
+
+    /// Removes the node with the given `ptr` in constant time by moving the last active node of the storage
+    /// into its position, and truncates the trailing positions; returns None if `ptr` does not belong to
+    /// an active node of this collection.
+    ///
+    /// Returns the data of the removed node together with the pointer that the moved node had before it is
+    /// moved into the position of `ptr`; None if no node is moved.
+    ///
+    /// Since the moved node is relocated, the caller is responsible for updating the references to it;
+    /// such as by `remap_reference(&moved_from, ptr)`.
+    pub(crate) fn swap_remove(
+        &mut self,
+        ptr: &NodePtr<V>,
+    ) -> Option<(V::Item, Option<NodePtr<V>>)> {
+        let pos = self.position_of(ptr)?;
+        if !self.nodes[pos].is_active() {
+            return None;
+        }
+        let moved_from = self.last_active_after(pos).map(|x| self.node_ptr_at_pos(x));
+        let (data, _) = self.swap_remove_active(pos);
+        Some((data, moved_from))
+    }
+
+    /// Redirects the previous and next references of all active nodes by `f`, which is called once
+    /// for each reference; the reference is replaced by the returned pointer if `f` returns `Some`,
+    /// and removed otherwise. The ends of the collection are not changed.
+    pub fn redirect_edges<F>(&mut self, mut f: F)
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        F: FnMut(&NodePtr<V>) -> Option<NodePtr<V>>,
+    {
+        fn redirect<V, R, F>(refs: &mut R, f: &mut F)
+        where
+            V: Variant,
+            R: NodeRefs<V>,
+            F: FnMut(&NodePtr<V>) -> Option<NodePtr<V>>,
+        {
+            let redirected: Vec<_> = refs.iter_ptr().map(&mut *f).collect();
+            let mut keep = redirected.iter().map(|x| x.is_some());
+            refs.retain_ptrs(|_| keep.next().unwrap_or(false));
+            for (ptr, new) in refs.iter_ptr_mut().zip(redirected.into_iter().flatten()) {
+                *ptr = new;
+            }
+        }
+
+        for pos in 0..self.nodes.len() {
+            let node = &mut self.nodes[pos];
+            if node.is_active() {
+                redirect(node.prev_mut(), &mut f);
+                redirect(node.next_mut(), &mut f);
+            }
+        }
+    }
+
+    /// Shrinks the heap memory allocated by the references of all active nodes, such as the capacities
+    /// of `RefsVec` references, without touching the underlying nodes storage.
+    pub fn shrink_references(&mut self)
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+    {
+        for pos in 0..self.nodes.len() {
+            let node = &mut self.nodes[pos];
+            if node.is_active() {
+                node.prev_mut().shrink_to_fit();
+                node.next_mut().shrink_to_fit();
+            }
+        }
+    }
+
+    /// Replaces every reference to the `old` node pointer with the `new` node pointer, among the
+    /// previous and next references of all active nodes and the ends of the collection.
+    ///
+    /// This is the primitive to keep the references valid after relocating a node by custom means.
+    pub fn remap_reference(&mut self, old: &NodePtr<V>, new: &NodePtr<V>)
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        let mut remap = |ptr: &mut NodePtr<V>| {
+            if ptr == old {
+                *ptr = new.clone();
+            }
+        };
+
+        for pos in 0..self.nodes.len() {
+            let node = &mut self.nodes[pos];
+            if node.is_active() {
+                node.prev_mut().iter_ptr_mut().for_each(&mut remap);
+                node.next_mut().iter_ptr_mut().for_each(&mut remap);
+            }
+        }
+        self.ends.iter_ptr_mut().for_each(remap);
+    }
+
+    /// Reclaims the closed nodes by moving active nodes from the back of the storage into the vacant
+    /// positions at the front, while never moving the nodes with the given `pinned` pointers; then,
+    /// truncates the trailing closed nodes.
+    ///
+    /// All references to the moved nodes are updated. Returns the pairs of the old and new pointers
+    /// of the moved nodes sorted by the old pointers.
+    pub(crate) fn compact_excluding_tracked(
+        &mut self,
+        pinned: &[NodePtr<V>],
+    ) -> Vec<(NodePtr<V>, NodePtr<V>)>
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        let mut is_pinned = alloc::vec![false; self.nodes.len()];
+        for pos in pinned.iter().filter_map(|x| self.position_of(x)) {
+            is_pinned[pos] = true;
+        }
+
+        let mut moves = Vec::new();
+        let mut vacant = 0;
+        let mut occupied = self.nodes.len();
+        loop {
+            while vacant < occupied && self.nodes[vacant].is_active() {
+                vacant += 1;
+            }
+            while occupied > vacant
+                && (self.nodes[occupied - 1].is_closed() || is_pinned[occupied - 1])
+            {
+                occupied -= 1;
+            }
+            if occupied <= vacant + 1 {
+                break;
+            }
+
+            occupied -= 1;
+            moves.push((self.node_ptr_at_pos(occupied), self.node_ptr_at_pos(vacant)));
+            self.move_node(vacant, occupied);
+        }
+
+        if !moves.is_empty() {
+            moves.sort_by_key(|(old, _)| old.ptr() as usize);
+            let remap = |ptr: &mut NodePtr<V>| {
+                if let Ok(i) =
+                    moves.binary_search_by_key(&(ptr.ptr() as usize), |(old, _)| old.ptr() as usize)
+                {
+                    *ptr = moves[i].1.clone();
+                }
+            };
+
+            for pos in 0..self.nodes.len() {
+                if let Some(node) = self.nodes.get_mut(pos) {
+                    node.prev_mut().iter_ptr_mut().for_each(remap);
+                    node.next_mut().iter_ptr_mut().for_each(remap);
+                }
+            }
+            self.ends.iter_ptr_mut().for_each(remap);
+        }
+
+        let num_used = (0..self.nodes.len())
+            .rev()
+            .find(|&i| self.nodes[i].is_active())
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.nodes.truncate(num_used);
+
+        moves
     }
 
     // data
@@ -225,15 +1121,70 @@ where
         let node = unsafe { &mut *node_ptr.ptr() };
         node.swap_data(new_value)
     }
+
+    /// Swaps the underlying data of the active nodes at the given storage positions `a` and `b`;
+    /// the references of the nodes are not changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the positions is out of bounds or if any of the nodes is closed.
+    pub fn swap_data_at(&mut self, a: usize, b: usize) {
+        let (ptr_a, ptr_b) = (self.node_ptr_at_pos(a), self.node_ptr_at_pos(b));
+        // SAFETY: both pointers belong to this collection which is mutably borrowed; and the nodes are
+        // distinct when `a != b`, so that the two mutable references do not alias.
+        let data_a = unsafe { &mut *ptr_a.ptr() }
+            .data_mut()
+            .expect("must be active");
+        if a == b {
+            return;
+        }
+        let data_b = unsafe { &mut *ptr_b.ptr() }
+            .data_mut()
+            .expect("must be active");
+        core::mem::swap(data_a, data_b);
+    }
 }
 
 impl<V> CoreCol<V, SplitVec<Node<V>, Recursive>>
 where
     V: Variant,
 {
+    /// Creates a new empty collection whose storage allocates `first_fragment` nodes for its first fragment;
+    /// the subsequent fragments grow by doubling.
+    ///
+    /// This avoids many small fragments when the collection is known to be large. A hint of zero is
+    /// treated as one.
+    pub fn new_with_fragment_hint(first_fragment: usize) -> Self {
+        let nodes: SplitVec<Node<V>, Recursive> = Vec::with_capacity(first_fragment.max(1)).into();
+        Self::from_raw_parts(nodes, Refs::empty(), 0)
+    }
+
     /// Appends the `nodes` to this collection.
     pub fn append_nodes(&mut self, nodes: SplitVec<Node<V>, Recursive>) {
-        self.len += nodes.len();
-        self.nodes.append(nodes)
+        self.len += (0..nodes.len()).filter(|&i| nodes[i].is_active()).count();
+        let capacity = self.nodes.capacity();
+        self.nodes.append(nodes);
+        self.track_growth(capacity);
+    }
+}
+
+impl<const N: usize, V, P> CoreCol<V, P>
+where
+    V: Variant<Ends = RefsArrayLeftMost<N, V>>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Pushes the node pointer to the ends, such as a new root of a forest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ends are full; i.e., already hold `N` references.
+    pub fn push_end(&mut self, node_ptr: NodePtr<V>) {
+        self.ends.push(node_ptr);
+    }
+
+    /// Removes the node pointer from the ends, shifting the subsequent ends to the left;
+    /// returns the position of the removed end, or None if the node is not an end.
+    pub fn remove_end(&mut self, node_ptr: &NodePtr<V>) -> Option<usize> {
+        self.ends.remove(node_ptr)
     }
 }
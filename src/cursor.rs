@@ -0,0 +1,187 @@
+use crate::{ListEnds, ListPrev, MemoryPolicy, Node, NodePtr, RefsSingle, SelfRefCol, Variant};
+use orx_pinned_vec::PinnedVec;
+
+/// A cursor over a singly or doubly linked list, which points to an element of the list or to
+/// the "ghost" position past the back and before the front of the list.
+///
+/// Moving to the previous element is a constant time operation for doubly linked lists;
+/// however, it requires a traversal from the front for singly linked lists.
+pub struct Cursor<'a, V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    col: &'a SelfRefCol<V, M, P>,
+    current: Option<NodePtr<V>>,
+}
+
+impl<'a, V, M, P> Cursor<'a, V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    pub(crate) fn new(col: &'a SelfRefCol<V, M, P>, current: Option<NodePtr<V>>) -> Self {
+        Self { col, current }
+    }
+
+    /// Returns a reference to the current element; None if the cursor is at the ghost position.
+    pub fn current(&self) -> Option<&'a V::Item> {
+        let col = self.col;
+        self.current.as_ref().and_then(|x| col.node(x).data())
+    }
+
+    /// Returns the pointer to the current node; None if the cursor is at the ghost position.
+    pub fn current_ptr(&self) -> Option<NodePtr<V>> {
+        self.current.clone()
+    }
+
+    /// Moves the cursor to the next element; moves to the ghost position from the back,
+    /// and to the front from the ghost position.
+    pub fn move_next(&mut self) {
+        self.current = match &self.current {
+            Some(ptr) => self.col.node(ptr).next().get(),
+            None => self.col.ends().front(),
+        };
+    }
+
+    /// Moves the cursor to the previous element; moves to the ghost position from the front,
+    /// and to the back from the ghost position.
+    pub fn move_prev(&mut self) {
+        self.current = match &self.current {
+            Some(ptr) => self.col.predecessor(ptr),
+            None => self.col.find_back_ptr(),
+        };
+    }
+}
+
+/// A cursor over a singly or doubly linked list which allows to mutate the list; it points to an
+/// element of the list or to the "ghost" position past the back and before the front of the list.
+///
+/// Nodes removed through the cursor are closed immediately, while closed nodes are reclaimed,
+/// if necessary, only once when the cursor is dropped.
+pub struct CursorMut<'a, V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    col: &'a mut SelfRefCol<V, M, P>,
+    current: Option<NodePtr<V>>,
+    last_closed: Option<NodePtr<V>>,
+}
+
+impl<'a, V, M, P> CursorMut<'a, V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    pub(crate) fn new(col: &'a mut SelfRefCol<V, M, P>, current: Option<NodePtr<V>>) -> Self {
+        Self {
+            col,
+            current,
+            last_closed: None,
+        }
+    }
+
+    /// Returns a reference to the current element; None if the cursor is at the ghost position.
+    pub fn current(&self) -> Option<&V::Item> {
+        self.current.as_ref().and_then(|x| self.col.node(x).data())
+    }
+
+    /// Returns a mutable reference to the current element; None if the cursor is at the ghost position.
+    pub fn current_mut(&mut self) -> Option<&mut V::Item> {
+        self.current
+            .as_ref()
+            .and_then(|x| self.col.node_mut(x).data_mut())
+    }
+
+    /// Returns the pointer to the current node; None if the cursor is at the ghost position.
+    pub fn current_ptr(&self) -> Option<NodePtr<V>> {
+        self.current.clone()
+    }
+
+    /// Moves the cursor to the next element; moves to the ghost position from the back,
+    /// and to the front from the ghost position.
+    pub fn move_next(&mut self) {
+        self.current = match &self.current {
+            Some(ptr) => self.col.node(ptr).next().get(),
+            None => self.col.ends().front(),
+        };
+    }
+
+    /// Moves the cursor to the previous element; moves to the ghost position from the front,
+    /// and to the back from the ghost position.
+    pub fn move_prev(&mut self) {
+        self.current = match &self.current {
+            Some(ptr) => self.col.predecessor(ptr),
+            None => self.col.find_back_ptr(),
+        };
+    }
+
+    /// Inserts the element with the given `data` after the current element, or to the front of the list
+    /// if the cursor is at the ghost position; the cursor does not move.
+    pub fn insert_after(&mut self, data: V::Item) {
+        let next = match &self.current {
+            Some(current) => self.col.node(current).next().get(),
+            None => self.col.ends().front(),
+        };
+        let ptr = self.col.push(data);
+
+        self.col.node_mut(&ptr).next_mut().set(next.clone());
+        self.col
+            .node_mut(&ptr)
+            .prev_mut()
+            .set_prev(self.current.clone());
+        match &self.current {
+            Some(current) => self.col.node_mut(current).next_mut().set_some(&ptr),
+            None => self.col.ends_mut().set_front(Some(ptr.clone())),
+        }
+        match &next {
+            Some(next) => self
+                .col
+                .node_mut(next)
+                .prev_mut()
+                .set_prev(Some(ptr.clone())),
+            None => self.col.ends_mut().set_back(Some(ptr)),
+        }
+    }
+
+    /// Removes the current element and returns it, moving the cursor to the next element;
+    /// returns None and does nothing if the cursor is at the ghost position.
+    pub fn remove_current(&mut self) -> Option<V::Item> {
+        let ptr = self.current.take()?;
+        let prev = self.col.predecessor(&ptr);
+        self.current = self.col.node(&ptr).next().get();
+
+        self.col.unlink(prev.as_ref(), &ptr);
+        let data = self.col.close(&ptr);
+        self.last_closed = Some(ptr);
+        Some(data)
+    }
+}
+
+impl<V, M, P> Drop for CursorMut<'_, V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn drop(&mut self) {
+        if let Some(ptr) = self.last_closed.take() {
+            self.col.reclaim(&ptr);
+        }
+    }
+}
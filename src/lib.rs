@@ -12,22 +12,47 @@
 )]
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod collection_error;
 mod common_traits;
 mod core_col;
+mod cursor;
+mod linked_list;
 mod memory;
+mod move_error;
 mod node;
+mod node_data;
 mod references;
 mod selfref_col;
+mod storage;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod structural_diff;
 mod variant;
 
+pub use collection_error::CollectionError;
+pub use common_traits::ActiveDataIter;
 pub use core_col::CoreCol;
+pub use cursor::{Cursor, CursorMut};
 pub use memory::{
-    MemoryPolicy, MemoryReclaimNever, MemoryReclaimOnThreshold, MemoryReclaimer, MemoryState,
-    Utilization,
+    DetailedUtilization, MemoryPolicy, MemoryReclaimNever, MemoryReclaimOnRuntimeThreshold,
+    MemoryReclaimOnThreshold, MemoryReclaimer, MemoryState, Stats, Then, Utilization,
 };
+pub use move_error::MoveError;
 pub use node::Node;
-pub use references::{NodeIdx, NodeIdxError, NodePtr};
-pub use references::{Refs, RefsArray, RefsNone, RefsSingle, RefsVec};
-pub use selfref_col::SelfRefCol;
+pub use node_data::{NodeData, NodeDataEagerClose, NodeDataLazyClose};
+pub use references::{
+    ArrayLeftMostPtrIter, ArrayPtrIter, ArrayPtrIterMut, NodeRefs, Refs, RefsArray,
+    RefsArrayLeftMost, RefsNone, RefsSingle, RefsVec,
+};
+pub use references::{
+    ListEnds, ListPrev, NodeIdx, NodeIdxError, NodePtr, ResolvedIdx, ResolvedIdxMut, UntypedNodeIdx,
+};
+pub use selfref_col::{DefaultPinnedVec, SelfRefCol, SelfRefColStd};
+pub use storage::PinnedVecWithCapacity;
+#[cfg(feature = "proptest")]
+pub use strategy::{linked_lists, list_ops, GeneratedList, ListOp};
+pub use structural_diff::StructuralDiff;
 pub use variant::Variant;
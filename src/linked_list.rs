@@ -0,0 +1,715 @@
+use crate::{
+    CoreCol, Cursor, CursorMut, ListEnds, ListPrev, MemoryPolicy, Node, NodeIdx, NodePtr, NodeRefs,
+    RefsArray, RefsSingle, SelfRefCol, Variant,
+};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Recursive, SplitVec};
+
+impl<V, M, P> SelfRefCol<V, M, P>
+where
+    V: Variant,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Returns a reference to the data at the front of the list; None if the list is empty.
+    pub fn front(&self) -> Option<&V::Item> {
+        self.ends().front().and_then(|x| self.node(&x).data())
+    }
+}
+
+impl<V, M, P> SelfRefCol<V, M, P>
+where
+    V: Variant<Ends = RefsArray<2, V>>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Returns a reference to the data at the back of the list; None if the list is empty.
+    pub fn back(&self) -> Option<&V::Item> {
+        self.ends().get(1).and_then(|x| self.node(&x).data())
+    }
+}
+
+impl<V, P> CoreCol<V, P>
+where
+    V: Variant<Ends = RefsArray<2, V>>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Swaps the front and back ends of the list.
+    pub fn swap_ends(&mut self) {
+        let (front, back) = (self.ends().get(0), self.ends().get(1));
+        self.ends_mut().set(0, back);
+        self.ends_mut().set(1, front);
+    }
+}
+
+impl<V, P> CoreCol<V, P>
+where
+    V: Variant<Prev = RefsSingle<V>, Next = RefsSingle<V>, Ends = RefsArray<2, V>>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Swaps the logical positions of the nodes `a` and `b` in the doubly linked list by relinking them
+    /// and their neighbors; the data and the positions of the nodes in the storage do not change.
+    ///
+    /// Both nodes are assumed to be active members of the list; swapping a node with itself does nothing.
+    pub fn swap_linked(&mut self, a: &NodePtr<V>, b: &NodePtr<V>) {
+        fn relink<V, P>(col: &mut CoreCol<V, P>, x: Option<&NodePtr<V>>, y: Option<&NodePtr<V>>)
+        where
+            V: Variant<Prev = RefsSingle<V>, Next = RefsSingle<V>, Ends = RefsArray<2, V>>,
+            P: PinnedVec<Node<V>>,
+        {
+            match x {
+                Some(x) => col.node_mut(x).next_mut().set(y.cloned()),
+                None => col.ends_mut().set(0, y.cloned()),
+            }
+            match y {
+                Some(y) => col.node_mut(y).prev_mut().set(x.cloned()),
+                None => col.ends_mut().set(1, x.cloned()),
+            }
+        }
+
+        if a == b {
+            return;
+        }
+
+        let (prev_a, next_a) = (self.node(a).prev().get(), self.node(a).next().get());
+        let (prev_b, next_b) = (self.node(b).prev().get(), self.node(b).next().get());
+
+        if next_a.as_ref() == Some(b) {
+            relink(self, prev_a.as_ref(), Some(b));
+            relink(self, Some(b), Some(a));
+            relink(self, Some(a), next_b.as_ref());
+        } else if next_b.as_ref() == Some(a) {
+            relink(self, prev_b.as_ref(), Some(a));
+            relink(self, Some(a), Some(b));
+            relink(self, Some(b), next_a.as_ref());
+        } else {
+            relink(self, prev_a.as_ref(), Some(b));
+            relink(self, Some(b), next_a.as_ref());
+            relink(self, prev_b.as_ref(), Some(a));
+            relink(self, Some(a), next_b.as_ref());
+        }
+    }
+
+    /// Relocates the nodes such that their order in the storage matches the order of the list from the
+    /// back to the front, which improves locality when the list is mostly traversed backward; then,
+    /// truncates the trailing closed nodes.
+    ///
+    /// All references to the moved nodes are updated. Returns whether or not any node is moved.
+    pub(crate) fn reorder_by_prev_chain(&mut self) -> bool {
+        let num_positions = self.nodes().len();
+        let mut chain = Vec::with_capacity(self.len());
+        let mut current = self.ends().back();
+        while let Some(ptr) = current.take().filter(|_| chain.len() < self.len()) {
+            let Some(pos) = self.position_of(&ptr) else {
+                break;
+            };
+            chain.push(pos);
+            current = self.node(&ptr).prev().get();
+        }
+
+        // current positions of the nodes initially at each position, and vice versa
+        let mut position_of: Vec<_> = (0..num_positions).collect();
+        let mut initial_at: Vec<_> = (0..num_positions).collect();
+        for (target, initial) in chain.into_iter().enumerate() {
+            let current = position_of[initial];
+            if current != target {
+                self.nodes_mut().swap(target, current);
+                let displaced = initial_at[target];
+                initial_at.swap(target, current);
+                position_of[initial] = target;
+                position_of[displaced] = current;
+            }
+        }
+
+        let mut moves: Vec<_> = (0..num_positions)
+            .filter(|&p| position_of[p] != p)
+            .map(|p| {
+                (
+                    self.node_ptr_at_pos(p),
+                    self.node_ptr_at_pos(position_of[p]),
+                )
+            })
+            .collect();
+        let nodes_moved = !moves.is_empty();
+
+        if nodes_moved {
+            self.count_moves(moves.len());
+            moves.sort_by_key(|(old, _)| old.ptr() as usize);
+            let remap = |ptr: &mut NodePtr<V>| {
+                if let Ok(i) =
+                    moves.binary_search_by_key(&(ptr.ptr() as usize), |(old, _)| old.ptr() as usize)
+                {
+                    *ptr = moves[i].1.clone();
+                }
+            };
+
+            for pos in 0..num_positions {
+                if let Some(node) = self.nodes_mut().get_mut(pos) {
+                    node.prev_mut().iter_ptr_mut().for_each(remap);
+                    node.next_mut().iter_ptr_mut().for_each(remap);
+                }
+            }
+            self.ends_mut().iter_ptr_mut().for_each(remap);
+        }
+
+        let num_used = (0..num_positions)
+            .rev()
+            .find(|&i| self.nodes()[i].is_active())
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        self.nodes_mut().truncate(num_used);
+
+        nodes_moved
+    }
+}
+
+impl<V, M, P> SelfRefCol<V, M, P>
+where
+    V: Variant<Prev = RefsSingle<V>, Next = RefsSingle<V>, Ends = RefsArray<2, V>>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Relocates the nodes such that their order in the storage matches the order of the list from the
+    /// back to the front, which improves locality when the list is mostly traversed backward; then,
+    /// truncates the trailing closed nodes.
+    ///
+    /// All references to the moved nodes are updated. If any node is moved or any closed node is removed,
+    /// the memory state is advanced, which invalidates all node indices, and the reorganization is counted
+    /// as a reclaim. Returns whether or not the memory state changed.
+    pub fn reorder_by_prev_chain(&mut self) -> bool {
+        self.reclaim_with(|col| {
+            let storage_len = col.nodes().len();
+            let nodes_moved = CoreCol::reorder_by_prev_chain(col);
+            nodes_moved || col.nodes().len() < storage_len
+        })
+    }
+}
+
+/// Linked list operations for singly and doubly linked lists.
+///
+/// These methods assume that all active nodes of the collection belong to the list,
+/// which is linked from the front to the back by the `next` references.
+impl<V, M, P> SelfRefCol<V, M, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Returns a cursor pointing to the front of the list, or to the ghost position if the list is empty.
+    pub fn cursor_front(&self) -> Cursor<'_, V, M, P> {
+        Cursor::new(self, self.ends().front())
+    }
+
+    /// Returns a cursor which allows to mutate the list, pointing to the front of the list or to the
+    /// ghost position if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, V, M, P> {
+        let front = self.ends().front();
+        CursorMut::new(self, front)
+    }
+
+    /// Clones the data of the list into a vector in the order from the front to the back.
+    pub fn to_vec(&self) -> Vec<V::Item>
+    where
+        V::Item: Clone,
+    {
+        let mut vec = Vec::with_capacity(self.len());
+        let mut current = self.ends().front();
+        while let Some(ptr) = current {
+            let node = self.node(&ptr);
+            if let Some(data) = node.data() {
+                vec.push(data.clone());
+            }
+            current = node.next().get();
+        }
+        vec
+    }
+
+    /// Returns an iterator of mutable references to the data of the list in the order from the front
+    /// to the back, rather than the order of the nodes in the underlying storage.
+    ///
+    /// Each node is visited at most once; the iteration stops if the next references lead to an already
+    /// visited node, such as when the links form a cycle, or to a node which does not belong to the collection.
+    pub fn iter_data_mut_from_front(&mut self) -> impl Iterator<Item = &mut V::Item> + '_ {
+        let mut current = self.ends().front();
+        let mut visited = alloc::vec![false; self.nodes().len()];
+        let col: &Self = self;
+        core::iter::from_fn(move || {
+            let ptr = current.take()?;
+            let pos = col.position_of(&ptr)?;
+            match visited[pos] {
+                true => return None,
+                false => visited[pos] = true,
+            }
+            // SAFETY: the pointer belongs to this collection which is mutably borrowed by the iterator,
+            // and the node is not visited again which guarantees that mutable references do not alias.
+            let node = unsafe { &mut *ptr.ptr() };
+            current = node.next().get();
+            node.data_mut()
+        })
+    }
+
+    /// Removes all elements of the list satisfying the predicate `pred` and returns them in the order
+    /// from the front to the back; the remaining elements stay linked in their original order.
+    ///
+    /// Closed nodes are reclaimed, if necessary, only once after all removals.
+    pub fn extract_if<F>(&mut self, mut pred: F) -> Vec<V::Item>
+    where
+        F: FnMut(&V::Item) -> bool,
+    {
+        let mut extracted = Vec::new();
+        let mut last_closed = None;
+        let mut prev: Option<NodePtr<V>> = None;
+        let mut current = self.ends().front();
+
+        while let Some(ptr) = current {
+            let node = self.node(&ptr);
+            let next = node.next().get();
+            if !node.data().is_some_and(&mut pred) {
+                prev = Some(ptr);
+                current = next;
+                continue;
+            }
+
+            self.unlink(prev.as_ref(), &ptr);
+            extracted.push(self.close(&ptr));
+            last_closed = Some(ptr);
+            current = next;
+        }
+
+        if let Some(ptr) = last_closed {
+            self.reclaim(&ptr);
+        }
+
+        extracted
+    }
+
+    /// Visits the elements of the list from the front to the back with `f`, which may mutate each element
+    /// and returns whether or not to keep it; elements for which `f` returns false are removed.
+    ///
+    /// Closed nodes are reclaimed, if necessary, only once after all removals.
+    pub fn retain_mut_linked<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut V::Item) -> bool,
+    {
+        let mut last_closed = None;
+        let mut prev: Option<NodePtr<V>> = None;
+        let mut current = self.ends().front();
+
+        while let Some(ptr) = current {
+            let next = self.node(&ptr).next().get();
+            if self.node_mut(&ptr).data_mut().is_none_or(&mut f) {
+                prev = Some(ptr);
+                current = next;
+                continue;
+            }
+
+            self.unlink(prev.as_ref(), &ptr);
+            self.close(&ptr);
+            last_closed = Some(ptr);
+            current = next;
+        }
+
+        if let Some(ptr) = last_closed {
+            self.reclaim(&ptr);
+        }
+    }
+
+    /// Removes consecutive elements of the list which map to the same key, keeping the first of each run;
+    /// hence, all duplicates are removed if the list is sorted by the key.
+    ///
+    /// Closed nodes are reclaimed, if necessary, only once after all removals.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: PartialEq,
+        F: FnMut(&V::Item) -> K,
+    {
+        let Some(front) = self.ends().front() else {
+            return;
+        };
+        let mut survivor_key = self.node(&front).data().map(&mut key);
+        let mut survivor = front;
+        let mut last_closed = None;
+
+        while let Some(ptr) = self.node(&survivor).next().get() {
+            let ptr_key = self.node(&ptr).data().map(&mut key);
+            if ptr_key != survivor_key {
+                survivor = ptr;
+                survivor_key = ptr_key;
+                continue;
+            }
+
+            self.unlink(Some(&survivor), &ptr);
+            self.close(&ptr);
+            last_closed = Some(ptr);
+        }
+
+        if let Some(ptr) = last_closed {
+            self.reclaim(&ptr);
+        }
+    }
+
+    /// Consumes the list and partitions its elements into two new lists, where the first contains the
+    /// elements for which `f` returns true and the second contains the remaining elements.
+    ///
+    /// Each list keeps the original relative order of its elements, and is built on a fresh storage
+    /// with a fresh memory state.
+    pub fn partition<F>(mut self, mut f: F) -> (Self, Self)
+    where
+        F: FnMut(&V::Item) -> bool,
+        P: Default,
+    {
+        fn push_back<V, M, P>(
+            (col, back): &mut (SelfRefCol<V, M, P>, Option<NodePtr<V>>),
+            data: V::Item,
+        ) where
+            V: Variant<Next = RefsSingle<V>>,
+            V::Prev: ListPrev<V>,
+            V::Ends: ListEnds<V>,
+            M: MemoryPolicy<V>,
+            P: PinnedVec<Node<V>>,
+        {
+            let ptr = col.push(data);
+            match back {
+                Some(old_back) => {
+                    col.node_mut(old_back).next_mut().set_some(&ptr);
+                    col.node_mut(&ptr)
+                        .prev_mut()
+                        .set_prev(Some(old_back.clone()));
+                }
+                None => col.ends_mut().set_front(Some(ptr.clone())),
+            }
+            col.ends_mut().set_back(Some(ptr.clone()));
+            *back = Some(ptr);
+        }
+
+        let mut left = (Self::new(), None);
+        let mut right = (Self::new(), None);
+        let mut current = self.ends().front();
+        while let Some(ptr) = current {
+            current = self.node(&ptr).next().get();
+            let data = self.close(&ptr);
+            match f(&data) {
+                true => push_back(&mut left, data),
+                false => push_back(&mut right, data),
+            }
+        }
+        (left.0, right.0)
+    }
+
+    /// Inserts the `value` into its position in the sorted list and returns its index.
+    ///
+    /// The list is assumed to be sorted with respect to `cmp`. The new element is inserted after all
+    /// elements which compare as `Less` or `Equal`, and the position is found by traversing the list
+    /// from the front.
+    pub fn insert_sorted_by<F>(&mut self, value: V::Item, mut cmp: F) -> NodeIdx<V>
+    where
+        F: FnMut(&V::Item, &V::Item) -> Ordering,
+    {
+        let mut prev: Option<NodePtr<V>> = None;
+        let mut current = self.ends().front();
+        while let Some(ptr) = current.clone() {
+            let node = self.node(&ptr);
+            if node
+                .data()
+                .is_some_and(|x| cmp(x, &value) == Ordering::Greater)
+            {
+                break;
+            }
+            current = node.next().get();
+            prev = Some(ptr);
+        }
+
+        let ptr = self.push(value);
+        match &prev {
+            Some(prev) => self.node_mut(prev).next_mut().set_some(&ptr),
+            None => self.ends_mut().set_front(Some(ptr.clone())),
+        }
+        match &current {
+            Some(next) => self.node_mut(next).prev_mut().set_prev(Some(ptr.clone())),
+            None => self.ends_mut().set_back(Some(ptr.clone())),
+        }
+        let node = self.node_mut(&ptr);
+        node.prev_mut().set_prev(prev);
+        node.next_mut().set(current);
+
+        NodeIdx::new(self.memory_state(), &ptr)
+    }
+
+    /// Moves the first `n` nodes of the list to the back.
+    ///
+    /// The nodes are only relinked, they are not moved in the storage; therefore, the memory state
+    /// does not change and all node indices remain valid.
+    ///
+    /// Rotating by `n >= len` is equivalent to rotating by `n % len`; and rotating by zero does nothing.
+    pub fn rotate_front(&mut self, n: usize) {
+        let n = match self.len() {
+            0 | 1 => return,
+            len => n % len,
+        };
+        if n == 0 {
+            return;
+        }
+
+        let (Some(old_front), Some(old_back)) = (self.ends().front(), self.find_back_ptr()) else {
+            return;
+        };
+
+        let mut new_back = old_front.clone();
+        for _ in 1..n {
+            match self.node(&new_back).next().get() {
+                Some(next) => new_back = next,
+                None => return,
+            }
+        }
+        let Some(new_front) = self.node(&new_back).next().get() else {
+            return;
+        };
+
+        self.node_mut(&old_back).next_mut().set_some(&old_front);
+        self.node_mut(&old_front)
+            .prev_mut()
+            .set_prev(Some(old_back));
+        self.node_mut(&new_back).next_mut().set_none();
+        self.node_mut(&new_front).prev_mut().set_prev(None);
+
+        self.ends_mut().set_front(Some(new_front));
+        self.ends_mut().set_back(Some(new_back));
+    }
+
+    /// Pushes the element with the given `data` to the front of the list and returns its pointer.
+    #[cfg(feature = "proptest")]
+    pub(crate) fn push_front_linked(&mut self, data: V::Item) -> NodePtr<V> {
+        let old_front = self.ends().front();
+        let ptr = self.push(data);
+
+        self.node_mut(&ptr).next_mut().set(old_front.clone());
+        match &old_front {
+            Some(old_front) => self
+                .node_mut(old_front)
+                .prev_mut()
+                .set_prev(Some(ptr.clone())),
+            None => self.ends_mut().set_back(Some(ptr.clone())),
+        }
+        self.ends_mut().set_front(Some(ptr.clone()));
+
+        ptr
+    }
+
+    /// Removes and returns the element at the given `position` of the list, reclaiming closed nodes
+    /// if necessary; returns None if the position is out of bounds.
+    #[cfg(feature = "proptest")]
+    pub(crate) fn remove_linked_at(&mut self, position: usize) -> Option<V::Item> {
+        let mut prev = None;
+        let mut current = self.ends().front()?;
+        for _ in 0..position {
+            let next = self.node(&current).next().get()?;
+            prev = Some(current);
+            current = next;
+        }
+
+        self.unlink(prev.as_ref(), &current);
+        Some(self.close_and_reclaim(&current))
+    }
+
+    /// Unlinks the node with the given `ptr` from the list, linking its previous node `prev` to its next node.
+    pub(crate) fn unlink(&mut self, prev: Option<&NodePtr<V>>, ptr: &NodePtr<V>) {
+        let next = self.node(ptr).next().get();
+        match prev {
+            Some(prev) => self.node_mut(prev).next_mut().set(next.clone()),
+            None => self.ends_mut().set_front(next.clone()),
+        }
+        match &next {
+            Some(next) => self.node_mut(next).prev_mut().set_prev(prev.cloned()),
+            None => self.ends_mut().set_back(prev.cloned()),
+        }
+    }
+
+    /// Returns the pointer to the node preceding the node with the given `ptr`, which is read from the
+    /// previous reference if it is tracked, or found by traversing the list from the front otherwise.
+    pub(crate) fn predecessor(&self, ptr: &NodePtr<V>) -> Option<NodePtr<V>> {
+        self.node(ptr).prev().prev().or_else(|| {
+            let mut current = self.ends().front()?;
+            loop {
+                let next = self.node(&current).next().get()?;
+                if &next == ptr {
+                    return Some(current);
+                }
+                current = next;
+            }
+        })
+    }
+
+    /// Returns the pointer to the back of the list, which is read from the ends if it is tracked,
+    /// or found by traversing the list otherwise.
+    pub(crate) fn find_back_ptr(&self) -> Option<NodePtr<V>> {
+        self.ends().back().or_else(|| {
+            let mut current = self.ends().front()?;
+            while let Some(next) = self.node(&current).next().get() {
+                current = next;
+            }
+            Some(current)
+        })
+    }
+}
+
+impl<V, M> SelfRefCol<V, M, SplitVec<Node<V>, Recursive>>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+{
+    /// Appends the list of the `other` collection to the back of this list.
+    ///
+    /// The fragments of the recursive split vector of `other` are moved into this collection rather than
+    /// copying the nodes; therefore, nodes are not moved in memory and node pointers of both collections
+    /// remain valid. Node indices of `other`, however, are not valid for this collection.
+    pub fn append(&mut self, other: Self) {
+        let other_back = other.find_back_ptr();
+        let (other, _) = other.into_inner();
+        let (nodes, other_ends, _) = other.into_inner();
+        let Some(other_front) = other_ends.front() else {
+            self.append_nodes(nodes);
+            return;
+        };
+
+        let back = self.find_back_ptr();
+        self.append_nodes(nodes);
+
+        match back {
+            Some(back) => {
+                self.node_mut(&back).next_mut().set_some(&other_front);
+                self.node_mut(&other_front).prev_mut().set_prev(Some(back));
+            }
+            None => self.ends_mut().set_front(Some(other_front)),
+        }
+        self.ends_mut().set_back(other_back);
+    }
+}
+
+/// Linked list operations for singly and doubly linked lists.
+///
+/// These methods assume that all active nodes of the collection belong to the list,
+/// which is linked from the front to the back by the `next` references.
+impl<V, P> CoreCol<V, P>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Recovers the ends of the list by scanning the active nodes, such that the front is the unique node
+    /// which is not referenced as the next of any node, and the back is the unique node without a next.
+    ///
+    /// Returns whether or not the ends could be recomputed; if the active nodes do not form a single
+    /// list, the ends are left unchanged and false is returned.
+    pub fn recompute_ends_singly(&mut self) -> bool {
+        fn single(mut positions: impl Iterator<Item = usize>) -> Option<usize> {
+            positions.next().filter(|_| positions.next().is_none())
+        }
+
+        let mut is_referenced = alloc::vec![false; self.nodes().len()];
+        for pos in self.active_positions() {
+            let next = self.nodes()[pos].next().get();
+            if let Some(next) = next.and_then(|x| self.position_of(&x)) {
+                is_referenced[next] = true;
+            }
+        }
+
+        let front = single(self.active_positions().filter(|x| !is_referenced[*x]));
+        let back = single(
+            self.active_positions()
+                .filter(|x| self.nodes()[*x].next().get().is_none()),
+        );
+
+        let (front, back) = match (front, back) {
+            (Some(front), Some(back)) => (
+                Some(self.node_ptr_at_pos(front)),
+                Some(self.node_ptr_at_pos(back)),
+            ),
+            _ if self.is_empty() => (None, None),
+            _ => return false,
+        };
+
+        self.ends_mut().set_front(front);
+        self.ends_mut().set_back(back);
+        true
+    }
+
+    /// Links all active nodes into a list in the order of their positions in the underlying storage, and sets
+    /// the ends accordingly; existing links of the active nodes are overwritten.
+    ///
+    /// This allows to build a list by first pushing all elements as free nodes, and then linking them at once.
+    pub fn link_sequentially(&mut self) {
+        let positions: Vec<_> = self.active_positions().collect();
+        let mut prev: Option<NodePtr<V>> = None;
+        for pos in positions {
+            let ptr = self.node_ptr_at_pos(pos);
+            match &prev {
+                Some(prev) => self.node_mut(prev).next_mut().set_some(&ptr),
+                None => self.ends_mut().set_front(Some(ptr.clone())),
+            }
+            let node = self.node_mut(&ptr);
+            node.prev_mut().set_prev(prev);
+            node.next_mut().set(None);
+            prev = Some(ptr);
+        }
+        if prev.is_none() {
+            self.ends_mut().set_front(None);
+        }
+        self.ends_mut().set_back(prev);
+    }
+
+    /// Merges the list of the `other` collection into this list, such that the combined list is sorted
+    /// with respect to `cmp` provided that both lists are sorted.
+    ///
+    /// Elements of `other` are moved into new nodes of this collection; existing nodes of this collection
+    /// are not moved in the storage and only relinked. Among equal elements, those of this list come first.
+    pub fn merge_sorted_by<P2, F>(&mut self, mut other: CoreCol<V, P2>, mut cmp: F)
+    where
+        P2: PinnedVec<Node<V>>,
+        F: FnMut(&V::Item, &V::Item) -> Ordering,
+    {
+        let mut prev: Option<NodePtr<V>> = None;
+        let mut current = self.ends().front();
+        let mut other_current = other.ends().front();
+
+        while let Some(other_ptr) = other_current {
+            other_current = other.node(&other_ptr).next().get();
+            let data = other.close(&other_ptr);
+
+            while let Some(ptr) = current.clone() {
+                let node = self.node(&ptr);
+                if node
+                    .data()
+                    .is_some_and(|x| cmp(x, &data) == Ordering::Greater)
+                {
+                    break;
+                }
+                current = node.next().get();
+                prev = Some(ptr);
+            }
+
+            let ptr = self.push(data);
+            self.node_mut(&ptr).next_mut().set(current.clone());
+            self.node_mut(&ptr).prev_mut().set_prev(prev.clone());
+            match &prev {
+                Some(prev) => self.node_mut(prev).next_mut().set_some(&ptr),
+                None => self.ends_mut().set_front(Some(ptr.clone())),
+            }
+            match &current {
+                Some(current) => self
+                    .node_mut(current)
+                    .prev_mut()
+                    .set_prev(Some(ptr.clone())),
+                None => self.ends_mut().set_back(Some(ptr.clone())),
+            }
+            prev = Some(ptr);
+        }
+    }
+}
@@ -2,12 +2,18 @@ mod never;
 mod on_threshold;
 mod policy;
 mod reclaimer;
+mod runtime_threshold;
 mod state;
+mod stats;
+mod then;
 mod utilization;
 
 pub use never::MemoryReclaimNever;
 pub use on_threshold::MemoryReclaimOnThreshold;
 pub use policy::MemoryPolicy;
 pub use reclaimer::MemoryReclaimer;
+pub use runtime_threshold::MemoryReclaimOnRuntimeThreshold;
 pub use state::MemoryState;
-pub use utilization::Utilization;
+pub use stats::Stats;
+pub use then::Then;
+pub use utilization::{DetailedUtilization, Utilization};
@@ -39,22 +39,37 @@ where
     V: Variant,
     R: MemoryReclaimer<V>,
 {
-    fn reclaim_closed_nodes<P>(col: &mut CoreCol<V, P>, _closed_node_ptr: &NodePtr<V>) -> bool
+    fn reclaim_closed_nodes<P>(
+        &self,
+        col: &mut CoreCol<V, P>,
+        _closed_node_ptr: &NodePtr<V>,
+    ) -> bool
     where
         P: PinnedVec<Node<V>>,
     {
-        let num_active_nodes = col.len();
-        let used = col.nodes().len();
-        let allowed_vacant = used >> D;
-        let num_vacant = used - num_active_nodes;
+        reclaim_on_threshold::<V, R, P>(col, D as u32)
+    }
+}
+
+/// Reclaims closed nodes by `R` whenever the ratio of closed nodes to all nodes exceeds one over `2^shift`;
+/// returns whether or not nodes are moved.
+pub(super) fn reclaim_on_threshold<V, R, P>(col: &mut CoreCol<V, P>, shift: u32) -> bool
+where
+    V: Variant,
+    R: MemoryReclaimer<V>,
+    P: PinnedVec<Node<V>>,
+{
+    let num_active_nodes = col.len();
+    let used = col.nodes().len();
+    let allowed_vacant = used.checked_shr(shift).unwrap_or(0);
+    let num_vacant = used - num_active_nodes;
 
-        match num_vacant <= allowed_vacant {
-            true => false,
-            false => {
-                let nodes_moved = R::reclaim_nodes(col);
-                col.nodes_mut().truncate(num_active_nodes);
-                nodes_moved
-            }
+    match num_vacant <= allowed_vacant {
+        true => false,
+        false => {
+            let nodes_moved = R::reclaim_nodes(col);
+            col.nodes_mut().truncate(num_active_nodes);
+            nodes_moved
         }
     }
 }
@@ -42,10 +42,18 @@ pub trait MemoryPolicy<V: Variant>: Clone + Default {
     ///   * reclaims closed nodes whenever it is possible to refresh indices or prior indices are no longer required.
     /// * [`MemoryReclaimOnThreshold`]: Automatically reorganizes self whenever the utilization of memory falls
     ///   below a predefined threshold. This is the setting fitting most of the use cases.
+    /// * [`MemoryReclaimOnRuntimeThreshold`]: Same as the threshold policy, where the threshold is held by the
+    ///   policy instance owned by the collection and chosen at runtime.
     ///
     /// [`MemoryReclaimNever`]: crate::MemoryReclaimNever
     /// [`MemoryReclaimOnThreshold`]: crate::MemoryReclaimOnThreshold
-    fn reclaim_closed_nodes<P>(col: &mut CoreCol<V, P>, closed_node_ptr: &NodePtr<V>) -> bool
+    /// [`MemoryReclaimOnRuntimeThreshold`]: crate::MemoryReclaimOnRuntimeThreshold
+    #[must_use = "nodes may have moved; stored NodeIdx values may now be invalid"]
+    fn reclaim_closed_nodes<P>(
+        &self,
+        col: &mut CoreCol<V, P>,
+        closed_node_ptr: &NodePtr<V>,
+    ) -> bool
     where
         P: PinnedVec<Node<V>>;
 }
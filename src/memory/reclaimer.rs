@@ -7,6 +7,7 @@ where
     V: Variant,
 {
     /// Memory reclaimer which reorganizes the collection nodes and brings node utilization to 100%.
+    #[must_use = "nodes may have moved; stored NodeIdx values may now be invalid"]
     fn reclaim_nodes<P>(col: &mut CoreCol<V, P>) -> bool
     where
         P: PinnedVec<Node<V>>;
@@ -0,0 +1,67 @@
+use super::{on_threshold::reclaim_on_threshold, policy::MemoryPolicy, reclaimer::MemoryReclaimer};
+use crate::{CoreCol, Node, NodePtr, Variant};
+use core::marker::PhantomData;
+use orx_pinned_vec::PinnedVec;
+
+/// Memory reclaim policy which triggers the reclaim operation whenever the node utilization falls
+/// below a threshold which is chosen at runtime.
+///
+/// This is the runtime counterpart of [`MemoryReclaimOnThreshold`] where the const generic `D` is
+/// replaced by the `shift` field: memory of closed nodes will be reclaimed whenever the ratio of closed
+/// nodes to all nodes exceeds one over `2^shift`.
+///
+/// The trade-off is that, unlike the other policies, this policy is not zero-sized; the threshold is
+/// held by the policy instance owned by the collection.
+///
+/// [`MemoryReclaimOnThreshold`]: crate::MemoryReclaimOnThreshold
+pub struct MemoryReclaimOnRuntimeThreshold<V: Variant, R: MemoryReclaimer<V>> {
+    shift: u32,
+    phantom: PhantomData<(V, R)>,
+}
+
+impl<V: Variant, R: MemoryReclaimer<V>> MemoryReclaimOnRuntimeThreshold<V, R> {
+    const DEFAULT_SHIFT: u32 = 2;
+
+    /// Creates the policy reclaiming closed nodes whenever the ratio of closed nodes to all nodes
+    /// exceeds one over `2^shift`.
+    pub fn new(shift: u32) -> Self {
+        Self {
+            shift,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the shift determining the threshold.
+    pub fn shift(&self) -> u32 {
+        self.shift
+    }
+}
+
+impl<V: Variant, R: MemoryReclaimer<V>> Default for MemoryReclaimOnRuntimeThreshold<V, R> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_SHIFT)
+    }
+}
+
+impl<V: Variant, R: MemoryReclaimer<V>> Clone for MemoryReclaimOnRuntimeThreshold<V, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.shift)
+    }
+}
+
+impl<V, R> MemoryPolicy<V> for MemoryReclaimOnRuntimeThreshold<V, R>
+where
+    V: Variant,
+    R: MemoryReclaimer<V>,
+{
+    fn reclaim_closed_nodes<P>(
+        &self,
+        col: &mut CoreCol<V, P>,
+        _closed_node_ptr: &NodePtr<V>,
+    ) -> bool
+    where
+        P: PinnedVec<Node<V>>,
+    {
+        reclaim_on_threshold::<V, R, P>(col, self.shift)
+    }
+}
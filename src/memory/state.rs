@@ -1,11 +1,17 @@
 /// Memory state of a self referential collection.
+///
+/// The state is a generation counter which is incremented every time nodes of the collection are moved.
+/// The counter is 64 bits wide; hence, it cannot wrap around to a previously used generation in practice,
+/// which would otherwise make a very old stale index appear valid.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub struct MemoryState {
-    pub(crate) id: usize,
+    pub(crate) id: u64,
 }
 
 impl MemoryState {
     pub(crate) const fn successor_state(&self) -> Self {
-        Self { id: self.id + 1 }
+        Self {
+            id: self.id.wrapping_add(1),
+        }
     }
 }
@@ -0,0 +1,19 @@
+/// Statistics on the memory of a self referential collection over its lifetime.
+///
+/// The result contains the following bits of information:
+/// * `num_growths`: number of times the capacity of the underlying storage increased due to a push.
+/// * `num_reclaims`: number of times the memory policy reorganized or trimmed the underlying storage.
+/// * `num_nodes_moved_total`: total number of nodes moved while reclaiming closed nodes.
+/// * `peak_storage_len`: maximum length that the underlying storage has reached.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct Stats {
+    /// Number of times the capacity of the underlying storage increased due to a push.
+    pub num_growths: usize,
+    /// Number of times the memory policy reorganized or trimmed the underlying storage.
+    pub num_reclaims: usize,
+    /// Total number of nodes moved while reclaiming closed nodes; moves made outside of reclaiming,
+    /// such as by `swap_remove` or `try_move_node`, are not counted.
+    pub num_nodes_moved_total: usize,
+    /// Maximum length that the underlying storage has reached.
+    pub peak_storage_len: usize,
+}
@@ -0,0 +1,45 @@
+use super::reclaimer::MemoryReclaimer;
+use crate::{CoreCol, Node, Variant};
+use core::marker::PhantomData;
+use orx_pinned_vec::PinnedVec;
+
+/// Memory reclaimer composed of two reclaimers which are executed one after the other.
+///
+/// `Then<A, B>` first reorganizes the nodes by `A::reclaim_nodes`, and then by `B::reclaim_nodes`.
+/// It returns true if any of the two reclaimers moved nodes.
+///
+/// This allows to chain reclaim behaviors; such as, clustering nodes by a key with `A`
+/// and then trimming the holes with a standard compaction `B`.
+pub struct Then<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A, B> Default for Then<A, B> {
+    fn default() -> Self {
+        Self {
+            phantom: Default::default(),
+        }
+    }
+}
+
+impl<A, B> Clone for Then<A, B> {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl<V, A, B> MemoryReclaimer<V> for Then<A, B>
+where
+    V: Variant,
+    A: MemoryReclaimer<V>,
+    B: MemoryReclaimer<V>,
+{
+    fn reclaim_nodes<P>(col: &mut CoreCol<V, P>) -> bool
+    where
+        P: PinnedVec<Node<V>>,
+    {
+        let first_moved = A::reclaim_nodes(col);
+        let second_moved = B::reclaim_nodes(col);
+        first_moved || second_moved
+    }
+}
@@ -16,3 +16,19 @@ pub struct Utilization {
     /// Number of nodes which had been opened and closed afterwards; however, not yet reclaimed.
     pub num_closed_nodes: usize,
 }
+
+/// Detailed memory utilization of a self referential collection backed by a split vector.
+///
+/// In addition to the node [`Utilization`], the result contains:
+/// * `refs_heap_bytes`: number of bytes allocated on the heap by the references of all nodes,
+///   such as the capacities of `RefsVec` references.
+/// * `num_fragments`: number of fragments of the underlying split vector.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DetailedUtilization {
+    /// Node utilization of the underlying storage.
+    pub utilization: Utilization,
+    /// Number of bytes allocated on the heap by the references of all nodes.
+    pub refs_heap_bytes: usize,
+    /// Number of fragments of the underlying split vector.
+    pub num_fragments: usize,
+}
@@ -0,0 +1,31 @@
+use core::fmt::{Debug, Display};
+
+/// Error cases of an invalid node relocation within the storage of a self referential collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoveError {
+    /// OutOfBounds => Either of the positions is not less than the length of the storage.
+    OutOfBounds {
+        /// The position which is out of bounds.
+        position: usize,
+        /// Length of the storage.
+        len: usize,
+    },
+    /// NotForward => The closed position is not strictly less than the active position; nodes can only be
+    /// moved towards the front of the storage.
+    NotForward {
+        /// Position of the closed node, which is the destination of the move.
+        closed: usize,
+        /// Position of the active node, which is the source of the move.
+        active: usize,
+    },
+    /// SourceClosed => The node at the active position, which is the source of the move, is closed.
+    SourceClosed,
+    /// DestinationActive => The node at the closed position, which is the destination of the move, is active.
+    DestinationActive,
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <MoveError as Debug>::fmt(self, f)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Refs, Variant};
+use crate::{NodeData, NodePtr, NodeRefs, Refs, Variant};
 use core::fmt::Debug;
 
 /// Node of the self referential collection.
@@ -6,7 +6,7 @@ pub struct Node<V>
 where
     V: Variant,
 {
-    data: Option<V::Item>,
+    data: V::Storage,
     prev: V::Prev,
     next: V::Next,
 }
@@ -18,7 +18,15 @@ where
     /// Creates a new active node with the given `data`, and `prev` and `next` references.
     pub fn new_active(data: V::Item, prev: V::Prev, next: V::Next) -> Self {
         Self {
-            data: Some(data),
+            data: NodeData::active(data),
+            prev,
+            next,
+        }
+    }
+
+    pub(crate) fn from_parts(data: Option<V::Item>, prev: V::Prev, next: V::Next) -> Self {
+        Self {
+            data: NodeData::from_option(data),
             prev,
             next,
         }
@@ -27,7 +35,7 @@ where
     /// Creates a new active node with the given `data` but with no connections.
     pub fn new_free_node(data: V::Item) -> Self {
         Self {
-            data: Some(data),
+            data: NodeData::active(data),
             prev: Refs::empty(),
             next: Refs::empty(),
         }
@@ -37,14 +45,14 @@ where
 
     /// Takes and returns the data of the node, transitions the node into the closed state.
     pub fn into_data(self) -> Option<V::Item> {
-        self.data
+        self.data.into_option()
     }
 
     // ref
 
     /// Returns a reference to the data of the node; None if the node is already closed.
     pub fn data(&self) -> Option<&V::Item> {
-        self.data.as_ref()
+        self.data.get()
     }
 
     /// Returns a reference to the previous references.
@@ -60,20 +68,20 @@ where
     /// Returns true if the node is active, false if it is closed.
     #[inline(always)]
     pub fn is_active(&self) -> bool {
-        self.data.is_some()
+        self.data.is_active()
     }
 
     /// Returns true if the node is closed, false if it is active.
     #[inline(always)]
     pub fn is_closed(&self) -> bool {
-        self.data.is_none()
+        !self.data.is_active()
     }
 
     // mut
 
     /// Returns a mutable reference to the underlying data.
     pub fn data_mut(&mut self) -> Option<&mut V::Item> {
-        self.data.as_mut()
+        self.data.get_mut()
     }
 
     /// Returns a mutable reference to the previous references.
@@ -86,15 +94,45 @@ where
         &mut self.next
     }
 
+    /// Returns true if the next references of the node contain the node with the given `node_ptr`;
+    /// such as, whether or not it is a child of this node in a tree.
+    pub fn references_next(&self, node_ptr: &NodePtr<V>) -> bool
+    where
+        V::Next: NodeRefs<V>,
+    {
+        self.next.contains_ptr(node_ptr)
+    }
+
+    /// Returns true if the previous references of the node contain the node with the given `node_ptr`;
+    /// such as, whether or not it is the parent of this node in a tree.
+    pub fn references_prev(&self, node_ptr: &NodePtr<V>) -> bool
+    where
+        V::Prev: NodeRefs<V>,
+    {
+        self.prev.contains_ptr(node_ptr)
+    }
+
+    /// Takes out and returns the previous and next references of the node, leaving the node without
+    /// connections; the node remains active with its data intact.
+    pub fn detach(&mut self) -> (V::Prev, V::Next) {
+        let prev = core::mem::replace(&mut self.prev, Refs::empty());
+        let next = core::mem::replace(&mut self.next, Refs::empty());
+        (prev, next)
+    }
+
     /// Closes the node and returns its data, and clears its connections.
     ///
     /// # Panics
     ///
-    /// Panics if the node was already closed.
+    /// Panics if the node was already closed, or if its storage cannot be closed such as `NodeDataEagerClose`.
     pub fn close(&mut self) -> V::Item {
+        let data = self
+            .data
+            .close()
+            .expect("must be an open node with a closable storage");
         self.prev.clear();
         self.next.clear();
-        self.data.take().expect("must be an open node")
+        data
     }
 
     /// Swaps the data of the node with the `new_value` and returns the old value.
@@ -104,16 +142,13 @@ where
     /// Panics if the node was already closed.
     pub fn swap_data(&mut self, new_value: V::Item) -> V::Item {
         debug_assert!(self.is_active());
-        self.data.replace(new_value).expect("must be active")
+        self.data.swap_data(new_value).expect("must be active")
     }
 
-    /// Closes the node and returns its data.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the node was already closed.
+    /// Closes the node and returns its data; returns None if the node was already closed, or if its
+    /// storage cannot be closed such as `NodeDataEagerClose`, in which case the node remains active.
     pub fn take_data(&mut self) -> Option<V::Item> {
-        self.data.take()
+        self.data.close()
     }
 }
 
@@ -123,7 +158,7 @@ where
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Node")
-            .field("data", &self.data)
+            .field("data", &self.data())
             .field("prev", &self.prev)
             .field("next", &self.next)
             .finish()
@@ -0,0 +1,40 @@
+/// Storage of the data of a node, which defines how the node is closed.
+///
+/// * [`NodeDataLazyClose`] allows to close the node; the data is taken out while the node remains in the
+///   storage as a closed node until closed nodes are reclaimed by the memory policy.
+/// * [`NodeDataEagerClose`] stores the data directly and the node is always active; it avoids the cost of
+///   the closed state and fits collections whose nodes are never closed, such as append-only collections.
+///
+/// [`NodeDataLazyClose`]: crate::NodeDataLazyClose
+/// [`NodeDataEagerClose`]: crate::NodeDataEagerClose
+pub trait NodeData<T>: Sized {
+    /// Creates the storage of an active node with the given `value`.
+    fn active(value: T) -> Self;
+
+    /// Creates the storage of an active node if the `value` is of Some variant; of a closed node otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `value` is None while the storage cannot represent a closed node.
+    fn from_option(value: Option<T>) -> Self;
+
+    /// Returns true if the node is active, false if it is closed.
+    fn is_active(&self) -> bool;
+
+    /// Returns a reference to the data; None if the node is closed.
+    fn get(&self) -> Option<&T>;
+
+    /// Returns a mutable reference to the data; None if the node is closed.
+    fn get_mut(&mut self) -> Option<&mut T>;
+
+    /// Closes the node and returns its data; returns None if the node is already closed or if the
+    /// storage cannot represent a closed node, in which case the node remains intact.
+    fn close(&mut self) -> Option<T>;
+
+    /// Consumes the storage and returns the data; None if the node is closed.
+    fn into_option(self) -> Option<T>;
+
+    /// Replaces the data of an active node with the `new_value` and returns the old value;
+    /// returns None and leaves the storage unchanged if the node is closed.
+    fn swap_data(&mut self, new_value: T) -> Option<T>;
+}
@@ -0,0 +1,54 @@
+use super::data::NodeData;
+
+/// Node data storage which keeps the data directly, without the closed state; hence, nodes are always
+/// active and cannot be closed.
+///
+/// This fits collections which never close nodes, such as append-only collections, and avoids the
+/// memory and branching cost of the closed state. Closing such a node is not possible:
+/// [`Node::take_data`] returns None leaving the node intact, and [`Node::close`] panics.
+///
+/// [`Node::take_data`]: crate::Node::take_data
+/// [`Node::close`]: crate::Node::close
+#[derive(Clone, Debug)]
+pub struct NodeDataEagerClose<T>(T);
+
+impl<T> NodeData<T> for NodeDataEagerClose<T> {
+    #[inline(always)]
+    fn active(value: T) -> Self {
+        Self(value)
+    }
+
+    fn from_option(value: Option<T>) -> Self {
+        Self(value.expect("eager-close storage cannot represent a closed node"))
+    }
+
+    #[inline(always)]
+    fn is_active(&self) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    fn get(&self) -> Option<&T> {
+        Some(&self.0)
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        Some(&mut self.0)
+    }
+
+    #[inline(always)]
+    fn close(&mut self) -> Option<T> {
+        None
+    }
+
+    #[inline(always)]
+    fn into_option(self) -> Option<T> {
+        Some(self.0)
+    }
+
+    #[inline(always)]
+    fn swap_data(&mut self, new_value: T) -> Option<T> {
+        Some(core::mem::replace(&mut self.0, new_value))
+    }
+}
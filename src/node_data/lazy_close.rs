@@ -0,0 +1,52 @@
+use super::data::NodeData;
+
+/// Node data storage which allows to close nodes; closing takes the data out while the node
+/// remains in the storage as a closed node until it is reclaimed by the memory policy.
+///
+/// This is the storage of collections which remove elements, such as linked lists and trees.
+#[derive(Clone, Debug)]
+pub struct NodeDataLazyClose<T>(Option<T>);
+
+impl<T> NodeData<T> for NodeDataLazyClose<T> {
+    #[inline(always)]
+    fn active(value: T) -> Self {
+        Self(Some(value))
+    }
+
+    #[inline(always)]
+    fn from_option(value: Option<T>) -> Self {
+        Self(value)
+    }
+
+    #[inline(always)]
+    fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+
+    #[inline(always)]
+    fn get(&self) -> Option<&T> {
+        self.0.as_ref()
+    }
+
+    #[inline(always)]
+    fn get_mut(&mut self) -> Option<&mut T> {
+        self.0.as_mut()
+    }
+
+    #[inline(always)]
+    fn close(&mut self) -> Option<T> {
+        self.0.take()
+    }
+
+    #[inline(always)]
+    fn into_option(self) -> Option<T> {
+        self.0
+    }
+
+    fn swap_data(&mut self, new_value: T) -> Option<T> {
+        match self.0.as_mut() {
+            Some(data) => Some(core::mem::replace(data, new_value)),
+            None => None,
+        }
+    }
+}
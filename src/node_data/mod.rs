@@ -0,0 +1,7 @@
+mod data;
+mod eager_close;
+mod lazy_close;
+
+pub use data::NodeData;
+pub use eager_close::NodeDataEagerClose;
+pub use lazy_close::NodeDataLazyClose;
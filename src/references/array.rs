@@ -1,4 +1,8 @@
-use super::{refs::Refs, NodePtr};
+use super::{
+    iter::{ArrayPtrIter, ArrayPtrIterMut},
+    refs::Refs,
+    NodePtr, NodeRefs,
+};
 use crate::variant::Variant;
 use core::fmt::Debug;
 
@@ -31,19 +35,74 @@ where
         self.0.iter().all(|x| x.is_none())
     }
 
+    /// Returns the number of present references, which might be less than `N`
+    /// since some of the slots, including interior ones, might be empty.
+    fn len(&self) -> usize {
+        self.0.iter().filter(|x| x.is_some()).count()
+    }
+
     fn clear(&mut self) {
         self.0.iter_mut().for_each(|x| _ = x.take());
     }
 }
 
+impl<const N: usize, V: Variant> NodeRefs<V> for RefsArray<N, V> {
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter().flatten()
+    }
+
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter_mut().flatten()
+    }
+
+    fn retain_ptrs<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        for slot in self.0.iter_mut() {
+            if slot.as_ref().is_some_and(|x| !f(x)) {
+                *slot = None;
+            }
+        }
+    }
+
+    fn ptr_at(&self, ref_idx: usize) -> Option<&NodePtr<V>> {
+        self.0.get(ref_idx).and_then(|x| x.as_ref())
+    }
+}
+
 impl<const N: usize, V: Variant> RefsArray<N, V> {
     /// Returns the node pointer a the `ref_idx` position of the references array.
     pub fn get(&self, ref_idx: usize) -> Option<NodePtr<V>> {
         self.0[ref_idx].clone()
     }
 
+    /// Returns an iterator over the present node pointers, skipping the empty slots.
+    pub fn iter(&self) -> ArrayPtrIter<'_, V> {
+        ArrayPtrIter::new(&self.0)
+    }
+
+    /// Creates a new references array by applying `f` to each slot of this array.
+    pub fn map<F>(&self, f: F) -> RefsArray<N, V>
+    where
+        F: FnMut(Option<NodePtr<V>>) -> Option<NodePtr<V>>,
+    {
+        RefsArray(self.0.clone().map(f))
+    }
+
     // mut
 
+    /// Returns an iterator over mutable references to the present node pointers, skipping the empty slots.
+    pub fn iter_mut(&mut self) -> ArrayPtrIterMut<'_, V> {
+        ArrayPtrIterMut::new(&mut self.0)
+    }
+
     /// Sets the the node pointer a the `ref_idx` position of the references array to the given `node_idx`.
     pub fn set(&mut self, ref_idx: usize, node_idx: Option<NodePtr<V>>) {
         self.0[ref_idx] = node_idx;
@@ -58,4 +117,10 @@ impl<const N: usize, V: Variant> RefsArray<N, V> {
     pub fn set_none(&mut self, ref_idx: usize) {
         self.0[ref_idx] = None
     }
+
+    /// Un-sets the node pointers at positions `start..N` of the references array;
+    /// does nothing if `start` is greater than or equal to `N`.
+    pub fn clear_from(&mut self, start: usize) {
+        self.0.iter_mut().skip(start).for_each(|x| *x = None);
+    }
 }
@@ -0,0 +1,168 @@
+use super::{iter::ArrayLeftMostPtrIter, refs::Refs, NodePtr, NodeRefs};
+use crate::variant::Variant;
+use core::{cmp::Ordering, fmt::Debug};
+
+/// A bounded number of references which are always kept at the left-most positions of the array.
+///
+/// In other words, the first `len` slots of the array are always present, while the remaining
+/// `N - len` slots are always empty.
+pub struct RefsArrayLeftMost<const N: usize, V>
+where
+    V: Variant,
+{
+    array: [Option<NodePtr<V>>; N],
+    len: usize,
+}
+
+impl<const N: usize, V: Variant> Clone for RefsArrayLeftMost<N, V> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<const N: usize, V: Variant> Debug for RefsArrayLeftMost<N, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RefsArrayLeftMost")
+            .field("array", &self.array)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<const N: usize, V> Refs for RefsArrayLeftMost<N, V>
+where
+    V: Variant,
+{
+    fn empty() -> Self {
+        Self {
+            array: [const { None }; N],
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clear(&mut self) {
+        self.array.iter_mut().for_each(|x| _ = x.take());
+        self.len = 0;
+    }
+}
+
+impl<const N: usize, V: Variant> NodeRefs<V> for RefsArrayLeftMost<N, V> {
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.array[..self.len].iter().flatten()
+    }
+
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.array[..self.len].iter_mut().flatten()
+    }
+
+    fn retain_ptrs<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        let mut len = 0;
+        for i in 0..self.len {
+            if self.array[i].as_ref().is_some_and(&mut f) {
+                self.array.swap(len, i);
+                len += 1;
+            }
+        }
+        self.array[len..self.len].iter_mut().for_each(|x| *x = None);
+        self.len = len;
+    }
+}
+
+impl<const N: usize, V: Variant> RefsArrayLeftMost<N, V> {
+    /// Returns true if all `N` slots are present.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the node pointer at the `ref_idx` position of the references array;
+    /// None if `ref_idx` is not less than `len`.
+    pub fn get(&self, ref_idx: usize) -> Option<NodePtr<V>> {
+        match ref_idx < self.len {
+            true => self.array[ref_idx].clone(),
+            false => None,
+        }
+    }
+
+    /// Returns an iterator over the present node pointers.
+    pub fn iter(&self) -> ArrayLeftMostPtrIter<'_, V> {
+        ArrayLeftMostPtrIter::new(&self.array[..self.len])
+    }
+
+    /// Returns the slice of the present references; i.e., the first `len` slots of the array,
+    /// all of which are guaranteed to be `Some`.
+    pub fn present_slice(&self) -> &[Option<NodePtr<V>>] {
+        &self.array[..self.len]
+    }
+
+    /// Binary searches the present references with the comparator `f`, which compares each reference
+    /// with the target; the references are assumed to be sorted with respect to `f`.
+    ///
+    /// Returns `Ok` with the position of a matching reference, or `Err` with the position where a matching
+    /// reference could be inserted while keeping the references sorted.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&NodePtr<V>) -> Ordering,
+    {
+        self.array[..self.len].binary_search_by(|x| match x {
+            Some(x) => f(x),
+            None => Ordering::Greater,
+        })
+    }
+
+    // mut
+
+    /// Pushes the node pointer to the first empty slot of the references array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the references array is full.
+    pub fn push(&mut self, node_ptr: NodePtr<V>) {
+        assert!(self.len < N, "RefsArrayLeftMost is full");
+        self.array[self.len] = Some(node_ptr);
+        self.len += 1;
+    }
+
+    /// Removes the reference at the `ref_idx` position and shifts the subsequent references
+    /// to the left; returns the removed pointer, or None if `ref_idx` is not less than `len`.
+    pub fn remove_at(&mut self, ref_idx: usize) -> Option<NodePtr<V>> {
+        match ref_idx < self.len {
+            true => {
+                let removed = self.array[ref_idx].take();
+                self.array[ref_idx..self.len].rotate_left(1);
+                self.len -= 1;
+                removed
+            }
+            false => None,
+        }
+    }
+
+    /// Removes the reference to the node with the given `node_ptr` and shifts the subsequent
+    /// references to the left; returns the position of the removed pointer, or None if absent.
+    pub fn remove(&mut self, node_ptr: &NodePtr<V>) -> Option<usize> {
+        let ref_idx = self.array[..self.len]
+            .iter()
+            .position(|x| x.as_ref() == Some(node_ptr))?;
+        self.remove_at(ref_idx);
+        Some(ref_idx)
+    }
+}
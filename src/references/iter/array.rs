@@ -0,0 +1,85 @@
+use crate::{NodePtr, Variant};
+use core::iter::FusedIterator;
+
+/// Iterator over the present node pointers of a `RefsArray`, skipping the empty slots.
+pub struct ArrayPtrIter<'a, V: Variant> {
+    iter: core::slice::Iter<'a, Option<NodePtr<V>>>,
+    len: usize,
+}
+
+impl<'a, V: Variant> ArrayPtrIter<'a, V> {
+    pub(crate) fn new(slots: &'a [Option<NodePtr<V>>]) -> Self {
+        Self {
+            iter: slots.iter(),
+            len: slots.iter().filter(|x| x.is_some()).count(),
+        }
+    }
+}
+
+impl<V: Variant> Iterator for ArrayPtrIter<'_, V> {
+    type Item = NodePtr<V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ptr = self.iter.by_ref().flatten().next()?;
+        self.len -= 1;
+        Some(ptr.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V: Variant> DoubleEndedIterator for ArrayPtrIter<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ptr = self.iter.by_ref().rev().flatten().next()?;
+        self.len -= 1;
+        Some(ptr.clone())
+    }
+}
+
+impl<V: Variant> ExactSizeIterator for ArrayPtrIter<'_, V> {}
+
+impl<V: Variant> FusedIterator for ArrayPtrIter<'_, V> {}
+
+/// Iterator over mutable references to the present node pointers of a `RefsArray`, skipping the empty slots.
+pub struct ArrayPtrIterMut<'a, V: Variant> {
+    iter: core::slice::IterMut<'a, Option<NodePtr<V>>>,
+    len: usize,
+}
+
+impl<'a, V: Variant> ArrayPtrIterMut<'a, V> {
+    pub(crate) fn new(slots: &'a mut [Option<NodePtr<V>>]) -> Self {
+        let len = slots.iter().filter(|x| x.is_some()).count();
+        Self {
+            iter: slots.iter_mut(),
+            len,
+        }
+    }
+}
+
+impl<'a, V: Variant> Iterator for ArrayPtrIterMut<'a, V> {
+    type Item = &'a mut NodePtr<V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ptr = self.iter.by_ref().flatten().next()?;
+        self.len -= 1;
+        Some(ptr)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<V: Variant> DoubleEndedIterator for ArrayPtrIterMut<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ptr = self.iter.by_ref().rev().flatten().next()?;
+        self.len -= 1;
+        Some(ptr)
+    }
+}
+
+impl<V: Variant> ExactSizeIterator for ArrayPtrIterMut<'_, V> {}
+
+impl<V: Variant> FusedIterator for ArrayPtrIterMut<'_, V> {}
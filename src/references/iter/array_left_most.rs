@@ -0,0 +1,37 @@
+use crate::{NodePtr, Variant};
+use core::iter::FusedIterator;
+
+/// Iterator over the present node pointers of a `RefsArrayLeftMost`.
+pub struct ArrayLeftMostPtrIter<'a, V: Variant> {
+    iter: core::slice::Iter<'a, Option<NodePtr<V>>>,
+}
+
+impl<'a, V: Variant> ArrayLeftMostPtrIter<'a, V> {
+    pub(crate) fn new(present: &'a [Option<NodePtr<V>>]) -> Self {
+        Self {
+            iter: present.iter(),
+        }
+    }
+}
+
+impl<V: Variant> Iterator for ArrayLeftMostPtrIter<'_, V> {
+    type Item = NodePtr<V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().and_then(|x| x.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<V: Variant> DoubleEndedIterator for ArrayLeftMostPtrIter<'_, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().and_then(|x| x.clone())
+    }
+}
+
+impl<V: Variant> ExactSizeIterator for ArrayLeftMostPtrIter<'_, V> {}
+
+impl<V: Variant> FusedIterator for ArrayLeftMostPtrIter<'_, V> {}
@@ -0,0 +1,5 @@
+mod array;
+mod array_left_most;
+
+pub use array::{ArrayPtrIter, ArrayPtrIterMut};
+pub use array_left_most::ArrayLeftMostPtrIter;
@@ -0,0 +1,65 @@
+use super::{NodePtr, Refs, RefsArray, RefsSingle};
+use crate::Variant;
+
+/// Ends of a linked list.
+///
+/// * `RefsSingle` is the ends of a singly linked list, which only keeps track of the front.
+/// * `RefsArray<2, V>` is the ends of a doubly linked list, where the front and back are stored
+///   in the first and second positions, respectively.
+pub trait ListEnds<V: Variant>: Refs {
+    /// Returns the pointer to the front of the list; None if the list is empty.
+    fn front(&self) -> Option<NodePtr<V>>;
+
+    /// Returns the pointer to the back of the list; None if the list is empty or if the back
+    /// of the list is not tracked, as in singly linked lists.
+    fn back(&self) -> Option<NodePtr<V>>;
+
+    /// Sets the front of the list to the given `front`.
+    fn set_front(&mut self, front: Option<NodePtr<V>>);
+
+    /// Sets the back of the list to the given `back`; does nothing if the back of the list
+    /// is not tracked, as in singly linked lists.
+    fn set_back(&mut self, back: Option<NodePtr<V>>);
+}
+
+impl<V: Variant> ListEnds<V> for RefsSingle<V> {
+    #[inline(always)]
+    fn front(&self) -> Option<NodePtr<V>> {
+        self.get()
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<NodePtr<V>> {
+        None
+    }
+
+    #[inline(always)]
+    fn set_front(&mut self, front: Option<NodePtr<V>>) {
+        self.set(front)
+    }
+
+    #[inline(always)]
+    fn set_back(&mut self, _: Option<NodePtr<V>>) {}
+}
+
+impl<V: Variant> ListEnds<V> for RefsArray<2, V> {
+    #[inline(always)]
+    fn front(&self) -> Option<NodePtr<V>> {
+        self.get(0)
+    }
+
+    #[inline(always)]
+    fn back(&self) -> Option<NodePtr<V>> {
+        self.get(1)
+    }
+
+    #[inline(always)]
+    fn set_front(&mut self, front: Option<NodePtr<V>>) {
+        self.set(0, front)
+    }
+
+    #[inline(always)]
+    fn set_back(&mut self, back: Option<NodePtr<V>>) {
+        self.set(1, back)
+    }
+}
@@ -0,0 +1,38 @@
+use super::{NodePtr, Refs, RefsNone, RefsSingle};
+use crate::Variant;
+
+/// Previous references of a node of a linked list.
+///
+/// * `RefsNone` is the previous references of a singly linked list, which are not tracked.
+/// * `RefsSingle` is the previous reference of a doubly linked list.
+pub trait ListPrev<V: Variant>: Refs {
+    /// Returns the pointer to the previous node; None if this is the front or if the previous
+    /// node is not tracked, as in singly linked lists.
+    fn prev(&self) -> Option<NodePtr<V>>;
+
+    /// Sets the previous node to the given `prev`; does nothing if the previous node
+    /// is not tracked, as in singly linked lists.
+    fn set_prev(&mut self, prev: Option<NodePtr<V>>);
+}
+
+impl<V: Variant> ListPrev<V> for RefsNone {
+    #[inline(always)]
+    fn prev(&self) -> Option<NodePtr<V>> {
+        None
+    }
+
+    #[inline(always)]
+    fn set_prev(&mut self, _: Option<NodePtr<V>>) {}
+}
+
+impl<V: Variant> ListPrev<V> for RefsSingle<V> {
+    #[inline(always)]
+    fn prev(&self) -> Option<NodePtr<V>> {
+        self.get()
+    }
+
+    #[inline(always)]
+    fn set_prev(&mut self, prev: Option<NodePtr<V>>) {
+        self.set(prev)
+    }
+}
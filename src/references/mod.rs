@@ -1,17 +1,31 @@
 mod array;
+mod array_left_most;
+mod iter;
+mod list_ends;
+mod list_prev;
 mod node_idx;
 mod node_idx_error;
 mod node_ptr;
+mod node_refs;
 mod none;
 mod refs;
+mod resolved_idx;
 mod single;
+mod untyped_node_idx;
 mod vec;
 
 pub use array::RefsArray;
+pub use array_left_most::RefsArrayLeftMost;
+pub use iter::{ArrayLeftMostPtrIter, ArrayPtrIter, ArrayPtrIterMut};
+pub use list_ends::ListEnds;
+pub use list_prev::ListPrev;
 pub use node_idx::NodeIdx;
 pub use node_idx_error::NodeIdxError;
 pub use node_ptr::NodePtr;
+pub use node_refs::NodeRefs;
 pub use none::RefsNone;
 pub use refs::Refs;
+pub use resolved_idx::{ResolvedIdx, ResolvedIdxMut};
 pub use single::RefsSingle;
+pub use untyped_node_idx::UntypedNodeIdx;
 pub use vec::RefsVec;
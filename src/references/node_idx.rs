@@ -1,4 +1,4 @@
-use super::NodePtr;
+use super::{NodePtr, UntypedNodeIdx};
 use crate::{MemoryState, Node, Variant};
 use core::fmt::Debug;
 
@@ -19,6 +19,12 @@ impl<V: Variant> Clone for NodeIdx<V> {
     }
 }
 
+// SAFETY: the index can only be used to access the node through the collection, which validates it;
+// hence, sending or sharing the index itself is safe as long as the data of the node can be sent or shared.
+unsafe impl<V: Variant> Send for NodeIdx<V> where V::Item: Send {}
+
+unsafe impl<V: Variant> Sync for NodeIdx<V> where V::Item: Sync {}
+
 impl<V: Variant> Debug for NodeIdx<V> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("NodeIdx")
@@ -50,6 +56,17 @@ where
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn from_raw_parts(ptr: *mut Node<V>, state: MemoryState) -> Self {
+        Self { ptr, state }
+    }
+
+    /// Erases the variant type of the index; the typed index can be recovered by `UntypedNodeIdx::downcast`.
+    #[inline(always)]
+    pub fn erase(self) -> UntypedNodeIdx {
+        UntypedNodeIdx::new(self.ptr as usize, self.state)
+    }
+
     /// Checks whether or not the `state` of the index matches that of this index.
     #[inline(always)]
     pub fn is_in_state(&self, state: MemoryState) -> bool {
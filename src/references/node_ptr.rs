@@ -1,5 +1,5 @@
 use crate::{Node, Variant};
-use core::fmt::Debug;
+use core::{fmt::Debug, hash::Hash};
 
 /// A wrapper around a node pointer.
 pub struct NodePtr<V: Variant> {
@@ -12,6 +12,21 @@ impl<V: Variant> PartialEq for NodePtr<V> {
     }
 }
 
+impl<V: Variant> Eq for NodePtr<V> {}
+
+impl<V: Variant> Hash for NodePtr<V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+// SAFETY: the pointer can only be dereferenced through the collection that owns the node
+// or through the unsafe api; hence, sending or sharing the pointer itself is safe as long
+// as the data of the node can be sent or shared.
+unsafe impl<V: Variant> Send for NodePtr<V> where V::Item: Send {}
+
+unsafe impl<V: Variant> Sync for NodePtr<V> where V::Item: Sync {}
+
 impl<V: Variant> Debug for NodePtr<V> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("NodeIdx")
@@ -40,6 +55,12 @@ impl<V: Variant> NodePtr<V> {
         self.ptr
     }
 
+    /// Returns true if both pointers point to the same node.
+    #[inline(always)]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+
     // unsafe api
     /// Returns a reference to the node.
     ///
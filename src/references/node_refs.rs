@@ -0,0 +1,45 @@
+use super::{NodePtr, Refs};
+use crate::Variant;
+
+/// References of a node to the other nodes of the same collection, which allows to access the
+/// referenced node pointers without matching on the concrete references type.
+///
+/// It is implemented by all references types: `RefsNone`, `RefsSingle`, `RefsArray`, `RefsArrayLeftMost`
+/// and `RefsVec`.
+pub trait NodeRefs<V: Variant>: Refs {
+    /// Returns an iterator of references to the present node pointers.
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a;
+
+    /// Returns an iterator of mutable references to the present node pointers.
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a;
+
+    /// Retains only the node pointers satisfying the predicate `f`, removing the others.
+    fn retain_ptrs<F>(&mut self, f: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool;
+
+    /// Returns a reference to the node pointer in the `ref_idx`-th slot of the references; None if the slot
+    /// is empty or out of bounds.
+    ///
+    /// Slots are positional for arrays, while they are the present node pointers in order for the others.
+    fn ptr_at(&self, ref_idx: usize) -> Option<&NodePtr<V>> {
+        self.iter_ptr().nth(ref_idx)
+    }
+
+    /// Returns true if the references contain the node with the given `node_ptr`.
+    fn contains_ptr(&self, node_ptr: &NodePtr<V>) -> bool {
+        self.iter_ptr().any(|x| x == node_ptr)
+    }
+
+    /// Returns the number of bytes allocated on the heap by the references; zero for inline references.
+    fn heap_bytes(&self) -> usize {
+        0
+    }
+
+    /// Shrinks the heap memory allocated by the references as much as possible; does nothing for inline references.
+    fn shrink_to_fit(&mut self) {}
+}
@@ -1,4 +1,5 @@
-use super::refs::Refs;
+use super::{refs::Refs, NodePtr, NodeRefs};
+use crate::Variant;
 
 /// Zero-sized no-reference.0
 #[derive(Clone, Debug)]
@@ -13,5 +14,31 @@ impl Refs for RefsNone {
         true
     }
 
+    fn len(&self) -> usize {
+        0
+    }
+
     fn clear(&mut self) {}
 }
+
+impl<V: Variant> NodeRefs<V> for RefsNone {
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a,
+    {
+        core::iter::empty()
+    }
+
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a,
+    {
+        core::iter::empty()
+    }
+
+    fn retain_ptrs<F>(&mut self, _: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+    }
+}
@@ -8,6 +8,9 @@ pub trait Refs: Clone + Debug {
     /// Returns true if the references collection is empty.
     fn is_empty(&self) -> bool;
 
+    /// Returns the number of references that are present.
+    fn len(&self) -> usize;
+
     /// Clears the references.
     fn clear(&mut self);
 }
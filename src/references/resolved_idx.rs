@@ -0,0 +1,57 @@
+use super::NodePtr;
+use crate::Variant;
+
+/// A node index which is validated once against the collection it is resolved by, and which provides
+/// constant time access to the data of the node for as long as the collection is borrowed.
+///
+/// Since the collection cannot change while it is borrowed, the validity check is not repeated on each access.
+pub struct ResolvedIdx<'a, V: Variant> {
+    ptr: NodePtr<V>,
+    data: &'a V::Item,
+}
+
+impl<'a, V: Variant> ResolvedIdx<'a, V> {
+    pub(crate) fn new(ptr: NodePtr<V>, data: &'a V::Item) -> Self {
+        Self { ptr, data }
+    }
+
+    /// Returns the pointer to the resolved node.
+    pub fn ptr(&self) -> NodePtr<V> {
+        self.ptr.clone()
+    }
+
+    /// Returns a reference to the data of the resolved node.
+    pub fn data(&self) -> &'a V::Item {
+        self.data
+    }
+}
+
+/// A node index which is validated once against the collection it is resolved by, and which provides
+/// constant time mutable access to the data of the node for as long as the collection is mutably borrowed.
+///
+/// Since the collection cannot change while it is borrowed, the validity check is not repeated on each access.
+pub struct ResolvedIdxMut<'a, V: Variant> {
+    ptr: NodePtr<V>,
+    data: &'a mut V::Item,
+}
+
+impl<'a, V: Variant> ResolvedIdxMut<'a, V> {
+    pub(crate) fn new(ptr: NodePtr<V>, data: &'a mut V::Item) -> Self {
+        Self { ptr, data }
+    }
+
+    /// Returns the pointer to the resolved node.
+    pub fn ptr(&self) -> NodePtr<V> {
+        self.ptr.clone()
+    }
+
+    /// Returns a reference to the data of the resolved node.
+    pub fn data(&self) -> &V::Item {
+        self.data
+    }
+
+    /// Returns a mutable reference to the data of the resolved node.
+    pub fn data_mut(&mut self) -> &mut V::Item {
+        self.data
+    }
+}
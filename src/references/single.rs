@@ -1,4 +1,4 @@
-use super::{refs::Refs, NodePtr};
+use super::{refs::Refs, NodePtr, NodeRefs};
 use crate::variant::Variant;
 use core::fmt::Debug;
 
@@ -28,11 +28,55 @@ impl<V: Variant> Refs for RefsSingle<V> {
         self.0.is_none()
     }
 
+    fn len(&self) -> usize {
+        match self.0.is_some() {
+            true => 1,
+            false => 0,
+        }
+    }
+
     fn clear(&mut self) {
         _ = self.0.take();
     }
 }
 
+impl<V: Variant> From<NodePtr<V>> for RefsSingle<V> {
+    fn from(node_ptr: NodePtr<V>) -> Self {
+        Self(Some(node_ptr))
+    }
+}
+
+impl<V: Variant> From<Option<NodePtr<V>>> for RefsSingle<V> {
+    fn from(node_ptr: Option<NodePtr<V>>) -> Self {
+        Self(node_ptr)
+    }
+}
+
+impl<V: Variant> NodeRefs<V> for RefsSingle<V> {
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter()
+    }
+
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter_mut()
+    }
+
+    fn retain_ptrs<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        if self.0.as_ref().is_some_and(|x| !f(x)) {
+            self.0 = None;
+        }
+    }
+}
+
 impl<V: Variant> RefsSingle<V> {
     /// Returns the pointer to the referenced node.
     pub fn get(&self) -> Option<NodePtr<V>> {
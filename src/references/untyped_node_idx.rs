@@ -0,0 +1,31 @@
+use super::NodeIdx;
+use crate::{MemoryPolicy, MemoryState, Node, SelfRefCol, Variant};
+use orx_pinned_vec::PinnedVec;
+
+/// A node index with its variant type erased, which allows to store indices of collections of
+/// different variants in the same container.
+///
+/// It can only be converted back into a typed `NodeIdx` by `downcast`, which validates it against a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UntypedNodeIdx {
+    ptr: usize,
+    state: MemoryState,
+}
+
+impl UntypedNodeIdx {
+    pub(crate) fn new(ptr: usize, state: MemoryState) -> Self {
+        Self { ptr, state }
+    }
+
+    /// Converts the untyped index back into the node index of the collection `col`; returns None if
+    /// the index does not belong to `col`, or if it is invalid for `col` due to reorganization or removal.
+    pub fn downcast<V, M, P>(self, col: &SelfRefCol<V, M, P>) -> Option<NodeIdx<V>>
+    where
+        V: Variant,
+        M: MemoryPolicy<V>,
+        P: PinnedVec<Node<V>>,
+    {
+        let idx = NodeIdx::from_raw_parts(self.ptr as *mut Node<V>, self.state);
+        col.contains_idx(&idx).then_some(idx)
+    }
+}
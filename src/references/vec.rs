@@ -1,7 +1,8 @@
-use super::{refs::Refs, NodePtr};
-use crate::Variant;
+use super::{refs::Refs, NodePtr, NodeRefs};
+use crate::{MemoryPolicy, Node, SelfRefCol, Variant};
 use alloc::vec::Vec;
-use core::fmt::Debug;
+use core::{cmp::Ordering, fmt::Debug};
+use orx_pinned_vec::PinnedVec;
 
 /// A dynamic number of references.
 pub struct RefsVec<V>(Vec<NodePtr<V>>)
@@ -29,7 +30,151 @@ impl<V: Variant> Refs for RefsVec<V> {
         self.0.is_empty()
     }
 
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
     fn clear(&mut self) {
         self.0.clear();
     }
 }
+
+impl<V: Variant> NodeRefs<V> for RefsVec<V> {
+    fn iter_ptr<'a>(&'a self) -> impl Iterator<Item = &'a NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter()
+    }
+
+    fn iter_ptr_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut NodePtr<V>>
+    where
+        V: 'a,
+    {
+        self.0.iter_mut()
+    }
+
+    fn retain_ptrs<F>(&mut self, f: F)
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        self.0.retain(f);
+    }
+
+    fn heap_bytes(&self) -> usize {
+        self.0.capacity() * core::mem::size_of::<NodePtr<V>>()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit();
+    }
+}
+
+impl<V: Variant> RefsVec<V> {
+    /// Returns the number of references that can be held without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+
+    /// Returns the node pointer at the `ref_idx` position of the references vector;
+    /// None if the position is out of bounds.
+    pub fn get(&self, ref_idx: usize) -> Option<NodePtr<V>> {
+        self.0.get(ref_idx).cloned()
+    }
+
+    /// Returns an iterator of the referenced nodes which are still active in the collection `col`;
+    /// references to closed nodes or to nodes not belonging to `col` are skipped.
+    pub fn iter_active<'a, M, P>(
+        &'a self,
+        col: &'a SelfRefCol<V, M, P>,
+    ) -> impl Iterator<Item = &'a Node<V>>
+    where
+        M: MemoryPolicy<V>,
+        P: PinnedVec<Node<V>>,
+    {
+        self.0
+            .iter()
+            .filter_map(|x| col.position_of(x))
+            .filter_map(|pos| col.get_node(pos))
+            .filter(|node| node.is_active())
+    }
+
+    // mut
+
+    /// Pushes the node pointer to the end of the references.
+    pub fn push(&mut self, node_ptr: NodePtr<V>) {
+        self.0.push(node_ptr);
+    }
+
+    /// Returns true if any of the references satisfies the predicate `f`.
+    pub fn any<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        self.0.iter().any(f)
+    }
+
+    /// Returns true if all of the references satisfy the predicate `f`; true if there is no reference.
+    pub fn all<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&NodePtr<V>) -> bool,
+    {
+        self.0.iter().all(f)
+    }
+
+    /// Inserts the reference to the node with the given `node_ptr` into its position in the sorted references
+    /// and returns the position it is inserted at.
+    ///
+    /// The references are assumed to be sorted with respect to `f`, which compares each reference with
+    /// the inserted `node_ptr`. The new reference is inserted after all references which compare as
+    /// `Less` or `Equal`, and the position is found by binary search.
+    pub fn insert_sorted_by<F>(&mut self, node_ptr: NodePtr<V>, mut f: F) -> usize
+    where
+        F: FnMut(&NodePtr<V>) -> Ordering,
+    {
+        let ref_idx = self.0.partition_point(|x| f(x) != Ordering::Greater);
+        self.0.insert(ref_idx, node_ptr);
+        ref_idx
+    }
+
+    /// Removes the reference at the `ref_idx` position in constant time by replacing it with the last
+    /// reference, and returns the removed pointer.
+    ///
+    /// The order of the references is not preserved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ref_idx` is out of bounds.
+    pub fn swap_remove(&mut self, ref_idx: usize) -> NodePtr<V> {
+        self.0.swap_remove(ref_idx)
+    }
+
+    /// Removes the reference to the node with the given `node_ptr` by replacing it with the last
+    /// reference, and returns the removed pointer; returns None if the reference does not exist.
+    ///
+    /// The order of the references is not preserved.
+    pub fn swap_remove_ptr(&mut self, node_ptr: &NodePtr<V>) -> Option<NodePtr<V>> {
+        let ref_idx = self.0.iter().position(|x| x == node_ptr)?;
+        Some(self.0.swap_remove(ref_idx))
+    }
+
+    /// Rewrites each reference by replacing it with the pointer returned by `f`; such as when the
+    /// referenced nodes are moved to new positions, and hence, the stored pointers become stale.
+    ///
+    /// The order of the references is preserved.
+    pub fn remap<F>(&mut self, f: F)
+    where
+        F: Fn(&NodePtr<V>) -> NodePtr<V>,
+    {
+        for ptr in self.0.iter_mut() {
+            *ptr = f(ptr);
+        }
+    }
+
+    /// Clears the references and releases the memory allocated for them.
+    ///
+    /// Unlike `clear` which keeps the capacity, the capacity drops to zero after this call.
+    pub fn clear_and_free(&mut self) {
+        self.0 = Vec::new();
+    }
+}
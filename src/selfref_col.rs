@@ -1,10 +1,32 @@
 use crate::{
-    node::Node, CoreCol, MemoryPolicy, MemoryState, NodeIdx, NodeIdxError, NodePtr, Variant,
+    node::Node, CoreCol, DetailedUtilization, MemoryPolicy, MemoryReclaimNever, MemoryState,
+    NodeIdx, NodeIdxError, NodePtr, NodeRefs, PinnedVecWithCapacity, Refs, RefsSingle, ResolvedIdx,
+    ResolvedIdxMut, Stats, StructuralDiff, Variant,
 };
+#[cfg(feature = "reclaim-hook")]
+use alloc::boxed::Box;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::ops::{Deref, DerefMut};
 use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Growth, Recursive, SplitVec};
+
+/// Default pinned vector used as the underlying storage of the nodes; a split vector with recursive growth,
+/// which allows to append other storages without moving the nodes.
+pub type DefaultPinnedVec<V> = SplitVec<Node<V>, Recursive>;
+
+/// Self referential collection using the default storage `DefaultPinnedVec` and, unless specified,
+/// the memory policy `MemoryReclaimNever`.
+pub type SelfRefColStd<V, M = MemoryReclaimNever> = SelfRefCol<V, M, DefaultPinnedVec<V>>;
 
 /// `SelfRefCol` is a core data structure to conveniently build safe and efficient self referential collections, such as linked lists and trees.
+///
+/// # Thread Safety
+///
+/// `NodePtr` and `NodeIdx` are `Send` (`Sync`) if the element type `V::Item` is `Send` (`Sync`).
+/// Consequently, the collection is `Send` (`Sync`) whenever its elements, memory policy and storage are.
+///
+/// The callback registered by `on_reclaim` with the `reclaim-hook` feature is required
+/// to be `Send` and `Sync`; hence, enabling the feature does not affect the thread safety of the collection.
 pub struct SelfRefCol<V, M, P>
 where
     V: Variant,
@@ -14,6 +36,8 @@ where
     core: CoreCol<V, P>,
     policy: M,
     state: MemoryState,
+    #[cfg(feature = "reclaim-hook")]
+    on_reclaim: Option<Box<dyn FnMut(usize) + Send + Sync>>,
 }
 
 impl<V, M, P> Default for SelfRefCol<V, M, P>
@@ -51,6 +75,28 @@ where
     }
 }
 
+impl<V, M, P> AsRef<CoreCol<V, P>> for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn as_ref(&self) -> &CoreCol<V, P> {
+        &self.core
+    }
+}
+
+impl<V, M, P> AsMut<CoreCol<V, P>> for SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn as_mut(&mut self) -> &mut CoreCol<V, P> {
+        &mut self.core
+    }
+}
+
 impl<V, M, P> SelfRefCol<V, M, P>
 where
     V: Variant,
@@ -62,11 +108,21 @@ where
     where
         P: Default,
     {
-        Self {
-            core: CoreCol::new(),
-            policy: M::default(),
-            state: MemoryState::default(),
-        }
+        Self::from_raw_parts(CoreCol::new(), M::default(), MemoryState::default())
+    }
+
+    /// Creates a new collection using the given `pinned` vector as its underlying storage.
+    ///
+    /// This allows to use a pre-configured pinned vector, such as one with a specific capacity
+    /// or growth strategy.
+    ///
+    /// Nodes that the `pinned` vector contains are kept as they are; the active ones are counted as
+    /// the elements of the collection.
+    /// The created collection has empty ends and a fresh memory state.
+    pub fn from_pinned_vec(pinned: P) -> Self {
+        let len = pinned.iter().filter(|x| x.is_active()).count();
+        let core = CoreCol::from_raw_parts(pinned, Refs::empty(), len);
+        Self::from_raw_parts(core, M::default(), MemoryState::default())
     }
 
     /// Breaks the self referential collection into its core collection and memory state.
@@ -80,15 +136,14 @@ where
             core,
             policy,
             state,
+            #[cfg(feature = "reclaim-hook")]
+            on_reclaim: None,
         }
     }
 
     pub(crate) fn with_active_nodes(nodes: P) -> Self {
-        Self {
-            core: CoreCol::with_active_nodes(nodes),
-            policy: M::default(),
-            state: MemoryState::default(),
-        }
+        let core = CoreCol::with_active_nodes(nodes);
+        Self::from_raw_parts(core, M::default(), MemoryState::default())
     }
 
     // get
@@ -98,20 +153,200 @@ where
         self.state
     }
 
+    /// Returns the number of memory state transitions the collection has gone through, including the
+    /// transitions caused by clears and explicit invalidations; each transition invalidates the node
+    /// indices created before.
+    ///
+    /// The counter increases monotonically over the lifetime of the collection.
+    pub fn generations_elapsed(&self) -> u64 {
+        self.state.id
+    }
+
     /// Memory policy of the collection.
     pub fn memory(&self) -> &M {
         &self.policy
     }
 
+    pub(crate) fn memory_mut(&mut self) -> &mut M {
+        &mut self.policy
+    }
+
+    /// Statistics on the memory of the collection over its lifetime.
+    pub fn stats(&self) -> Stats {
+        self.core.stats()
+    }
+
+    /// Returns the nodes of the collection as a single contiguous slice, which allows fast bulk processing
+    /// of the active data; returns None if the nodes are not contiguous.
+    ///
+    /// The slice is returned if and only if both of the following conditions hold:
+    /// * there are no holes; i.e., there exists no closed node which is not yet reclaimed, and
+    /// * the underlying storage holds all nodes in a single contiguous memory, such as a `FixedVec`
+    ///   or a `SplitVec` whose nodes fit in its first fragment.
+    ///
+    /// Note that the data cannot be viewed as a slice of `V::Item` since each node also holds its references;
+    /// the data of the nodes in the slice are all `Some`.
+    pub fn try_as_contiguous_nodes(&self) -> Option<&[Node<V>]> {
+        if self.len() != self.nodes().len() {
+            return None;
+        }
+        let mut slices = self.nodes().slices(..).into_iter();
+        match (slices.next(), slices.next()) {
+            (None, _) => Some(&[]),
+            (Some(slice), None) => Some(slice),
+            (Some(_), Some(_)) => None,
+        }
+    }
+
     /// Closes the node with the given `node_ptr`, returns its taken out value,
     /// and reclaims closed nodes if necessary.
     pub fn close_and_reclaim(&mut self, node_ptr: &NodePtr<V>) -> V::Item {
         let data = self.core.close(node_ptr);
+        self.reclaim(node_ptr);
+        data
+    }
+
+    /// Closes the node with the given `node_ptr`, returns its taken out value, and reclaims closed nodes
+    /// if necessary.
+    ///
+    /// Additionally returns whether or not nodes are moved by reclaiming; if true, all node indices
+    /// created prior to this call are invalidated.
+    #[must_use = "nodes may have moved; stored NodeIdx values may now be invalid"]
+    pub fn close_and_reclaim_signaling(&mut self, node_ptr: &NodePtr<V>) -> (V::Item, bool) {
+        let data = self.core.close(node_ptr);
+        let nodes_moved = self.reclaim(node_ptr);
+        (data, nodes_moved)
+    }
+
+    /// Returns the differences between the structures of this and the `other` collection; the result is
+    /// empty if both collections have the same data and references at the same positions.
+    ///
+    /// References are compared by the positions of the nodes they point to, and nodes are compared
+    /// position by position up to the longer of the two storages.
+    pub fn diff(&self, other: &Self) -> Vec<StructuralDiff>
+    where
+        V::Item: PartialEq,
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        let mut diffs = Vec::new();
+
+        if self.len() != other.len() {
+            diffs.push(StructuralDiff::ActiveCountMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
 
-        let state_changed = M::reclaim_closed_nodes(self, node_ptr);
+        for position in 0..self.nodes().len().max(other.nodes().len()) {
+            let (left, right) = (self.get_node(position), other.get_node(position));
+            if left.and_then(|x| x.data()) != right.and_then(|x| x.data()) {
+                diffs.push(StructuralDiff::DataMismatch { position });
+            }
+
+            let prev = (
+                left.map(|x| self.ref_positions(x.prev())),
+                right.map(|x| other.ref_positions(x.prev())),
+            );
+            if prev.0.unwrap_or_default() != prev.1.unwrap_or_default() {
+                diffs.push(StructuralDiff::PrevMismatch { position });
+            }
+
+            let next = (
+                left.map(|x| self.ref_positions(x.next())),
+                right.map(|x| other.ref_positions(x.next())),
+            );
+            if next.0.unwrap_or_default() != next.1.unwrap_or_default() {
+                diffs.push(StructuralDiff::NextMismatch { position });
+            }
+        }
+
+        if self.ref_positions(self.ends()) != other.ref_positions(other.ends()) {
+            diffs.push(StructuralDiff::EndsMismatch);
+        }
+
+        diffs
+    }
+
+    /// Returns the positions of the nodes referenced by `refs`; None for pointers not belonging to this collection.
+    fn ref_positions<R: NodeRefs<V>>(&self, refs: &R) -> Vec<Option<usize>> {
+        refs.iter_ptr().map(|x| self.position_of(x)).collect()
+    }
+
+    /// Closes the nodes with the given `idxs` and returns their taken out values, and reclaims closed nodes
+    /// if necessary only once after all nodes are closed.
+    ///
+    /// All indices are resolved against the current memory state before any node is closed; hence,
+    /// a reclaim cannot invalidate the remaining indices midway. The result is None for indices which
+    /// are invalid or which refer to an already removed node, including duplicates.
+    ///
+    /// Note that the references to the removed nodes from other nodes are not updated.
+    pub fn remove_indices(&mut self, idxs: &[NodeIdx<V>]) -> Vec<Option<V::Item>> {
+        let ptrs: Vec<_> = idxs.iter().map(|x| self.try_get_ptr(x).ok()).collect();
+
+        let mut last_closed = None;
+        let removed = ptrs
+            .into_iter()
+            .map(|ptr| {
+                let ptr = ptr.filter(|x| self.node(x).is_active())?;
+                let data = self.core.close(&ptr);
+                last_closed = Some(ptr);
+                Some(data)
+            })
+            .collect();
+
+        if let Some(ptr) = last_closed {
+            self.reclaim(&ptr);
+        }
+
+        removed
+    }
+
+    /// Reclaims closed nodes if necessary after the node with the given `closed_node_ptr` is closed;
+    /// returns whether or not nodes are moved.
+    pub(crate) fn reclaim(&mut self, closed_node_ptr: &NodePtr<V>) -> bool {
+        self.reclaim_with(|col| {
+            col.policy
+                .reclaim_closed_nodes(&mut col.core, closed_node_ptr)
+        })
+    }
+
+    /// Reclaims closed nodes by `reclaim_nodes` which returns whether or not nodes are moved;
+    /// updates the statistics and memory state, and calls the reclaim callback, if any.
+    pub(crate) fn reclaim_with(&mut self, reclaim_nodes: impl FnOnce(&mut Self) -> bool) -> bool {
+        let storage_len = self.nodes().len();
+        let num_moves = self.core.num_moves();
+        let state_changed = reclaim_nodes(self);
+        let num_moved = self.core.num_moves() - num_moves;
+        self.core.stats_mut().num_nodes_moved_total += num_moved;
+        if state_changed || self.nodes().len() < storage_len {
+            self.core.stats_mut().num_reclaims += 1;
+        }
         self.update_state(state_changed);
 
-        data
+        #[cfg(feature = "reclaim-hook")]
+        if state_changed {
+            if let Some(on_reclaim) = self.on_reclaim.as_mut() {
+                on_reclaim(num_moved);
+            }
+        }
+
+        state_changed
+    }
+
+    /// Registers the callback `f` which is called with the number of moved nodes whenever
+    /// reclaiming closed nodes moves nodes; replaces the previously registered callback, if any.
+    ///
+    /// Nodes moved by `move_node` are counted; reclaimers which relocate nodes by other means
+    /// might lead to a count less than the actual number of moved nodes.
+    ///
+    /// The callback belongs to this collection instance and is not carried over to collections created from it;
+    /// i.e., collections created by `clone`, `clone_from`, `clone_reachable_from` or by the `From` conversions
+    /// between memory policies do not have a callback registered.
+    #[cfg(feature = "reclaim-hook")]
+    pub fn on_reclaim(&mut self, f: impl FnMut(usize) + Send + Sync + 'static) {
+        self.on_reclaim = Some(Box::new(f));
     }
 
     /// If `state_changed` is true, proceeds to the next memory state.
@@ -164,14 +399,339 @@ where
         }
     }
 
+    /// Returns true if the `idx` is valid for this collection and refers to an active node;
+    /// i.e., if `try_get_ptr(idx)` would succeed.
+    #[inline(always)]
+    pub fn contains_idx(&self, idx: &NodeIdx<V>) -> bool {
+        self.try_get_ptr(idx).is_ok()
+    }
+
+    /// Validates the `idx` once and returns a handle providing constant time access to the data of the node
+    /// for the lifetime of the borrow; returns None if the index is invalid or refers to a removed node.
+    ///
+    /// This amortizes the validity check, which requires searching the pointer in the underlying storage,
+    /// when the same node is accessed repeatedly.
+    pub fn resolve(&self, idx: &NodeIdx<V>) -> Option<ResolvedIdx<'_, V>> {
+        let ptr = self.try_get_ptr(idx).ok()?;
+        let data = unsafe { &*ptr.ptr() }.data()?;
+        Some(ResolvedIdx::new(ptr, data))
+    }
+
+    /// Validates the `idx` once and returns a handle providing constant time mutable access to the data
+    /// of the node for the lifetime of the borrow; returns None if the index is invalid or refers to a
+    /// removed node.
+    pub fn resolve_mut(&mut self, idx: &NodeIdx<V>) -> Option<ResolvedIdxMut<'_, V>> {
+        let ptr = self.try_get_ptr(idx).ok()?;
+        let data = unsafe { &mut *ptr.ptr() }.data_mut()?;
+        Some(ResolvedIdxMut::new(ptr, data))
+    }
+
+    /// Retains only the entries of the `map` whose node indices are still valid for this collection;
+    /// such as, to prune a table of handles after a reclaim invalidates some of them.
+    #[cfg(feature = "std")]
+    pub fn filter_valid_indices<K, S>(
+        &self,
+        map: &mut std::collections::HashMap<K, NodeIdx<V>, S>,
+    ) {
+        map.retain(|_, idx| self.contains_idx(idx));
+    }
+
+    /// Removes the active node at the given storage position `pos` in constant time by moving
+    /// the last active node of the storage into `pos`, and truncates the trailing positions.
+    ///
+    /// Returns the data of the removed node together with the previous position of the node which
+    /// is moved into `pos`; None if no node is moved.
+    ///
+    /// Since the moved node is relocated, the caller is responsible for updating the references
+    /// to the moved node, which can be obtained by `node_ptr_at_pos(pos)`. Further, since positions
+    /// of the removed and moved nodes might later be occupied by other nodes, the memory state is
+    /// advanced, which invalidates all node indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pos` is out of bounds or if the node at `pos` is already closed.
+    pub fn swap_remove_active(&mut self, pos: usize) -> (V::Item, Option<usize>) {
+        let removed = self.core.swap_remove_active(pos);
+        self.update_state(true);
+        removed
+    }
+
+    /// Removes the node with the given `ptr` in constant time by moving the last active node of the storage
+    /// into its position, and truncates the trailing positions; returns None if `ptr` does not belong to
+    /// an active node of this collection.
+    ///
+    /// Returns the data of the removed node together with the pointer that the moved node had before it is
+    /// moved into the position of `ptr`; None if no node is moved.
+    ///
+    /// Since the moved node is relocated, the caller is responsible for updating the references to it;
+    /// such as by `remap_reference(&moved_from, ptr)`. Further, if a node is removed, the memory state is
+    /// advanced, which invalidates all node indices.
+    pub fn swap_remove(&mut self, ptr: &NodePtr<V>) -> Option<(V::Item, Option<NodePtr<V>>)> {
+        let removed = self.core.swap_remove(ptr);
+        self.update_state(removed.is_some());
+        removed
+    }
+
+    /// Reclaims the closed nodes by moving active nodes from the back of the storage into the vacant
+    /// positions at the front, while never moving the nodes with the given `pinned` pointers; then,
+    /// truncates the trailing closed nodes.
+    ///
+    /// All references to the moved nodes are updated and pointers of the pinned nodes remain valid.
+    /// If any node is moved or any closed node is removed, the memory state is advanced, which invalidates
+    /// all node indices including those of the pinned nodes, and the compaction is counted as a reclaim.
+    /// Returns whether or not the memory state changed.
+    pub fn compact_excluding(&mut self, pinned: &[NodePtr<V>]) -> bool
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        self.reclaim_with(|col| {
+            let storage_len = col.nodes().len();
+            let nodes_moved = !col.core.compact_excluding_tracked(pinned).is_empty();
+            nodes_moved || col.nodes().len() < storage_len
+        })
+    }
+
+    /// Reclaims all closed nodes by compacting the collection, and updates the `handles` such that
+    /// each index points to the new location of its node in the new memory state; entries whose
+    /// indices are already invalid are removed.
+    ///
+    /// All references to the moved nodes are updated; the memory state changes only if a node is moved or
+    /// closed nodes are removed from the storage. Like any other reclaim, this is counted by the statistics
+    /// and triggers the reclaim callback.
+    #[cfg(feature = "std")]
+    pub fn compact_and_remap<K, S>(
+        &mut self,
+        handles: &mut std::collections::HashMap<K, NodeIdx<V>, S>,
+    ) where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        self.filter_valid_indices(handles);
+
+        let mut moves = Vec::new();
+        let state_changed = self.reclaim_with(|col| {
+            let storage_len = col.nodes().len();
+            moves = col.core.compact_excluding_tracked(&[]);
+            !moves.is_empty() || col.nodes().len() < storage_len
+        });
+        if !state_changed {
+            return;
+        }
+
+        for idx in handles.values_mut() {
+            let old = idx.ptr() as usize;
+            let ptr = match moves.binary_search_by_key(&old, |(old, _)| old.ptr() as usize) {
+                Ok(i) => moves[i].1.clone(),
+                Err(_) => NodePtr::new(idx.ptr()),
+            };
+            *idx = NodeIdx::new(self.state, &ptr);
+        }
+    }
+
+    /// Returns the index of the active node at the given `position` of the underlying storage;
+    /// returns None if the position is out of bounds or the node at the position is closed.
+    ///
+    /// The index remains valid as long as the memory state does not change.
+    pub fn idx_at_position(&self, position: usize) -> Option<NodeIdx<V>> {
+        match self.nodes().get(position)?.is_active() {
+            true => Some(NodeIdx::new(self.state, &self.node_ptr_at_pos(position))),
+            false => None,
+        }
+    }
+
+    /// Creates a new collection containing clones of the nodes reachable from the node with the given `start`
+    /// index by following the next references; returns None if the index is invalid.
+    ///
+    /// Nodes are stored in breadth-first order; hence, the start node is at position zero of the new collection.
+    /// References among the cloned nodes are rewritten to point to the new nodes, while references to nodes
+    /// outside of the reachable set, including those of the ends, are dropped.
+    pub fn clone_reachable_from(&self, start: &NodeIdx<V>) -> Option<Self>
+    where
+        V::Item: Clone,
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+        P: Default,
+    {
+        fn remap<V, R, P, F>(refs: &mut R, nodes: &P, new_position: F)
+        where
+            V: Variant,
+            R: NodeRefs<V>,
+            P: PinnedVec<Node<V>>,
+            F: Fn(&NodePtr<V>) -> Option<usize>,
+        {
+            refs.retain_ptrs(|x| new_position(x).is_some());
+            for ptr in refs.iter_ptr_mut() {
+                if let Some(new) = new_position(ptr).and_then(|x| nodes.get_ptr(x)) {
+                    *ptr = NodePtr::new(new);
+                }
+            }
+        }
+
+        let start = self.try_get_ptr(start).ok()?;
+        let start = self.position_of(&start)?;
+
+        let mut new_positions = alloc::vec![None; self.nodes().len()];
+        let mut order = alloc::vec![start];
+        new_positions[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            for ptr in self.nodes()[pos].next().iter_ptr() {
+                let Some(next) = self.position_of(ptr) else {
+                    continue;
+                };
+                if new_positions[next].is_none() && self.nodes()[next].is_active() {
+                    new_positions[next] = Some(order.len());
+                    order.push(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut nodes = P::default();
+        for &pos in &order {
+            let node = &self.nodes()[pos];
+            let data = node.data().cloned();
+            nodes.push(Node::from_parts(
+                data,
+                node.prev().clone(),
+                node.next().clone(),
+            ));
+        }
+
+        let new_position = |ptr: &NodePtr<V>| self.position_of(ptr).and_then(|x| new_positions[x]);
+        for pos in 0..nodes.len() {
+            let mut prev = nodes[pos].prev().clone();
+            remap(&mut prev, &nodes, new_position);
+            let mut next = nodes[pos].next().clone();
+            remap(&mut next, &nodes, new_position);
+            *nodes[pos].prev_mut() = prev;
+            *nodes[pos].next_mut() = next;
+        }
+        let mut ends = self.ends().clone();
+        remap(&mut ends, &nodes, new_position);
+
+        let core = CoreCol::from_raw_parts(nodes, ends, order.len());
+        Some(Self::from_raw_parts(
+            core,
+            M::default(),
+            MemoryState::default(),
+        ))
+    }
+
+    /// Returns an iterator over the nodes of the tree in level order; i.e., performs a breadth-first traversal
+    /// starting from the root, which is the node referenced by the ends, visiting the children of each node
+    /// in the order of its next references.
+    ///
+    /// The iterator is empty if the collection does not have a root.
+    pub fn iter_level_order(&self) -> impl Iterator<Item = &Node<V>> + '_
+    where
+        V: Variant<Ends = RefsSingle<V>>,
+        V::Next: NodeRefs<V>,
+    {
+        let mut queue: VecDeque<_> = self.ends().get().into_iter().collect();
+        core::iter::from_fn(move || {
+            let node = self.node(&queue.pop_front()?);
+            queue.extend(node.next().iter_ptr().cloned());
+            Some(node)
+        })
+    }
+
     // mut
 
+    /// Pushes the element with the given `data` as a free node and returns its index if the length of the
+    /// collection is less than `max`; otherwise, returns back the `data` as the error without pushing it.
+    pub fn push_bounded(&mut self, data: V::Item, max: usize) -> Result<NodeIdx<V>, V::Item> {
+        match self.len() < max {
+            true => {
+                let ptr = self.core.push(data);
+                Ok(NodeIdx::new(self.state, &ptr))
+            }
+            false => Err(data),
+        }
+    }
+
+    /// Returns the index of the first active node, in the order of positions in the underlying storage,
+    /// whose data `matches`; or pushes the element created by `make` as a free node and returns its index
+    /// if there exists no such node.
+    pub fn get_or_insert_with<F, G>(&mut self, mut matches: F, make: G) -> NodeIdx<V>
+    where
+        F: FnMut(&V::Item) -> bool,
+        G: FnOnce() -> V::Item,
+    {
+        let existing =
+            (0..self.nodes().len()).find(|pos| self.nodes()[*pos].data().is_some_and(&mut matches));
+        let ptr = match existing {
+            Some(pos) => self.node_ptr_at_pos(pos),
+            None => self.core.push(make()),
+        };
+        NodeIdx::new(self.state, &ptr)
+    }
+
+    /// Advances the memory state without moving any node, which explicitly invalidates all
+    /// previously created node indices.
+    ///
+    /// This is useful when the collection is reused for an unrelated task and the outstanding indices
+    /// must no longer access its nodes.
+    pub fn invalidate_all_indices(&mut self) {
+        self.state = self.state.successor_state();
+    }
+
     /// Clears the collection and changes the memory state.
     pub fn clear(&mut self) {
         self.core.clear_core();
         self.state = self.state.successor_state();
     }
 
+    /// Clears the collection and re-populates it with the items of the given `iter` which are
+    /// pushed as free nodes; i.e., no links are established among the nodes.
+    ///
+    /// The memory state is advanced once, and the memory already allocated by the underlying
+    /// storage is reused as much as its `clear` method allows.
+    pub fn refill<I: IntoIterator<Item = V::Item>>(&mut self, iter: I) {
+        self.clear();
+        for data in iter.into_iter() {
+            self.core.push(data);
+        }
+    }
+
+    /// Clears the `dst` collection and clones the nodes of this collection into it, such that `dst` has
+    /// the same layout, data and structure, while the references point to the nodes of `dst`.
+    ///
+    /// The allocation of `dst` is reused as much as the `clear` method of its storage allows, and its
+    /// memory state is advanced.
+    ///
+    /// Only the nodes are cloned; `dst` keeps its own memory policy. Use `clone_from` to also copy the policy.
+    pub fn clone_structure_into(&self, dst: &mut Self)
+    where
+        V::Item: Clone,
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+        V::Ends: NodeRefs<V>,
+    {
+        self.core.clone_into_with(&mut dst.core, V::Item::clone);
+        dst.state = dst.state.successor_state();
+    }
+
+    /// Takes out the data of all active nodes, in the order of their positions in the underlying storage,
+    /// leaving behind an empty collection; the structure among the nodes is discarded.
+    ///
+    /// The core collection is swapped with an empty one and the memory state is advanced, while the
+    /// statistics of the collection are kept.
+    pub fn take(&mut self) -> Vec<V::Item>
+    where
+        P: Default,
+    {
+        let stats = self.core.stats();
+        let core = core::mem::take(&mut self.core);
+        *self.core.stats_mut() = stats;
+        self.state = self.state.successor_state();
+        core.into_active_data().collect()
+    }
+
     /// Returns a mutable reference to the node with the given `NodeIdx`;
     /// returns None if the index is invalid.
     #[inline(always)]
@@ -197,4 +757,110 @@ where
             false => Err(NodeIdxError::OutOfBounds),
         }
     }
+
+    /// Swaps the data of the node with the given `idx` with the `new_value`,
+    /// and returns the old value.
+    ///
+    /// Returns the error if the index is invalid or the node is already removed.
+    pub fn swap_data_by_idx(
+        &mut self,
+        idx: &NodeIdx<V>,
+        new_value: V::Item,
+    ) -> Result<V::Item, NodeIdxError> {
+        let ptr = self.try_get_ptr(idx)?;
+        Ok(self.core.swap_data(&ptr, new_value))
+    }
+
+    /// Returns mutable references to the data of the nodes with the given `idxs`.
+    ///
+    /// Returns None if any of the indices is invalid or refers to a removed node,
+    /// or if the indices are not pairwise distinct.
+    pub fn data_many_mut<const K: usize>(
+        &mut self,
+        idxs: [&NodeIdx<V>; K],
+    ) -> Option<[&mut V::Item; K]> {
+        let mut ptrs = [core::ptr::null_mut::<Node<V>>(); K];
+        for (i, idx) in idxs.iter().enumerate() {
+            let ptr = self.try_get_ptr(idx).ok()?.ptr();
+            if ptrs[..i].contains(&ptr) {
+                return None;
+            }
+            ptrs[i] = ptr;
+        }
+
+        let mut data = [core::ptr::null_mut::<V::Item>(); K];
+        for (i, ptr) in ptrs.into_iter().enumerate() {
+            data[i] = unsafe { &mut *ptr }.data_mut()? as *mut V::Item;
+        }
+
+        Some(data.map(|x| unsafe { &mut *x }))
+    }
+
+    /// Returns mutable references to the data of the nodes with the given `ptrs`.
+    ///
+    /// Returns None if any of the pointers does not belong to this collection or refers to a closed node,
+    /// or if the pointers are not pairwise distinct.
+    pub fn data_disjoint_mut<const K: usize>(
+        &mut self,
+        ptrs: [&NodePtr<V>; K],
+    ) -> Option<[&mut V::Item; K]> {
+        for (i, ptr) in ptrs.iter().enumerate() {
+            self.position_of(ptr)?;
+            if ptrs[..i].contains(ptr) {
+                return None;
+            }
+        }
+
+        let mut data = [core::ptr::null_mut::<V::Item>(); K];
+        for (i, ptr) in ptrs.into_iter().enumerate() {
+            data[i] = unsafe { &mut *ptr.ptr() }.data_mut()? as *mut V::Item;
+        }
+
+        Some(data.map(|x| unsafe { &mut *x }))
+    }
+}
+
+impl<V, M, G> SelfRefCol<V, M, SplitVec<Node<V>, G>>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    G: Growth,
+{
+    /// Returns the detailed memory utilization of the collection, which extends the node utilization
+    /// with the heap bytes of the references and the number of fragments of the split vector.
+    pub fn detailed_utilization(&self) -> DetailedUtilization
+    where
+        V::Prev: NodeRefs<V>,
+        V::Next: NodeRefs<V>,
+    {
+        DetailedUtilization {
+            utilization: self.utilization(),
+            refs_heap_bytes: self.refs_heap_bytes(),
+            num_fragments: self.nodes().fragments().len(),
+        }
+    }
+}
+
+impl<V, M, P> SelfRefCol<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVecWithCapacity<Node<V>>,
+{
+    /// Creates a new empty collection whose storage is created with the given `capacity`,
+    /// using the default memory policy.
+    ///
+    /// A fixed vector storage can never grow beyond the `capacity`, while a split vector storage
+    /// uses it as the capacity of its first fragment.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_policy(capacity, M::default())
+    }
+
+    /// Creates a new empty collection whose storage is created with the given `capacity`,
+    /// using the given `policy` instance, which allows to use policies carrying runtime state.
+    pub fn with_capacity_and_policy(capacity: usize, policy: M) -> Self {
+        let nodes = P::with_initial_capacity(capacity);
+        let core = CoreCol::from_raw_parts(nodes, Refs::empty(), 0);
+        Self::from_raw_parts(core, policy, MemoryState::default())
+    }
 }
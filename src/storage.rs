@@ -0,0 +1,26 @@
+use alloc::vec::Vec;
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Recursive, SplitVec};
+
+/// A pinned vector which can be created with an initial capacity; and hence, can be used as the storage
+/// of collections created by `SelfRefCol::with_capacity` and `SelfRefCol::with_capacity_and_policy`.
+pub trait PinnedVecWithCapacity<T>: PinnedVec<T> {
+    /// Creates a new empty pinned vector with the given initial `capacity`.
+    fn with_initial_capacity(capacity: usize) -> Self;
+}
+
+/// The fixed vector can never grow beyond the `capacity`.
+impl<T> PinnedVecWithCapacity<T> for FixedVec<T> {
+    fn with_initial_capacity(capacity: usize) -> Self {
+        FixedVec::new(capacity)
+    }
+}
+
+/// The `capacity` is the capacity of the first fragment; the split vector grows beyond it by allocating
+/// new fragments. A capacity of zero is treated as one.
+impl<T> PinnedVecWithCapacity<T> for SplitVec<T, Recursive> {
+    fn with_initial_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity.max(1)).into()
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{ListEnds, ListPrev, MemoryPolicy, Node, RefsSingle, SelfRefCol, Variant};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use orx_pinned_vec::PinnedVec;
+use proptest::{collection::vec, prelude::*};
+
+/// An operation on a linked list which is used to generate arbitrary linked lists.
+#[derive(Clone, Debug)]
+pub enum ListOp<T> {
+    /// Pushes the element to the front of the list.
+    PushFront(T),
+    /// Pops the element at the front of the list; does nothing if the list is empty.
+    PopFront,
+    /// Removes the element at position `i % len` of the list; does nothing if the list is empty.
+    Remove(usize),
+}
+
+/// A linked list generated by applying a sequence of operations on an empty collection.
+///
+/// Since the list is built only by pushing, popping and removing elements, followed by
+/// reclaiming closed nodes as determined by the memory policy, it is always valid:
+/// * all active nodes of the collection belong to the list, and
+/// * the list is linked from the front to the back by the `next` references.
+pub struct GeneratedList<V, M, P>
+where
+    V: Variant,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    /// Operations applied on an empty collection to generate the list.
+    pub ops: Vec<ListOp<V::Item>>,
+    /// The generated list.
+    pub col: SelfRefCol<V, M, P>,
+}
+
+impl<V, M, P> Debug for GeneratedList<V, M, P>
+where
+    V: Variant,
+    V::Item: Debug,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GeneratedList")
+            .field("ops", &self.ops)
+            .field("len", &self.col.len())
+            .finish()
+    }
+}
+
+/// Returns a strategy generating sequences of at most `max_num_ops` linked list operations,
+/// where the pushed elements are generated by the `item` strategy.
+pub fn list_ops<S>(item: S, max_num_ops: usize) -> impl Strategy<Value = Vec<ListOp<S::Value>>>
+where
+    S: Strategy,
+    S::Value: Clone,
+{
+    let op = prop_oneof![
+        2 => item.prop_map(ListOp::PushFront),
+        1 => Just(ListOp::PopFront),
+        1 => any::<usize>().prop_map(ListOp::Remove),
+    ];
+    vec(op, 0..=max_num_ops)
+}
+
+/// Returns a strategy generating singly or doubly linked lists by applying at most `max_num_ops`
+/// operations generated by [`list_ops`] on an empty collection.
+pub fn linked_lists<V, M, P, S>(
+    item: S,
+    max_num_ops: usize,
+) -> impl Strategy<Value = GeneratedList<V, M, P>>
+where
+    V: Variant<Next = RefsSingle<V>>,
+    V::Item: Clone + Debug,
+    V::Prev: ListPrev<V>,
+    V::Ends: ListEnds<V>,
+    M: MemoryPolicy<V>,
+    P: PinnedVec<Node<V>> + Default,
+    S: Strategy<Value = V::Item>,
+{
+    list_ops(item, max_num_ops).prop_map(|ops| {
+        let mut col = SelfRefCol::new();
+        for op in &ops {
+            match op {
+                ListOp::PushFront(x) => _ = col.push_front_linked(x.clone()),
+                ListOp::PopFront => _ = col.remove_linked_at(0),
+                ListOp::Remove(i) => match col.len() {
+                    0 => {}
+                    len => _ = col.remove_linked_at(i % len),
+                },
+            }
+        }
+        GeneratedList { ops, col }
+    })
+}
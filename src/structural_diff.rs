@@ -0,0 +1,31 @@
+/// A difference between the structures of two self referential collections.
+///
+/// Positions refer to the positions of the nodes in the underlying storages; and references are
+/// compared by the positions of the nodes they point to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuralDiff {
+    /// ActiveCountMismatch => Collections have different numbers of active nodes.
+    ActiveCountMismatch {
+        /// Number of active nodes of the left collection.
+        left: usize,
+        /// Number of active nodes of the right collection.
+        right: usize,
+    },
+    /// DataMismatch => Nodes at the `position` have different data, or only one of them is active.
+    DataMismatch {
+        /// Position of the nodes in the storages.
+        position: usize,
+    },
+    /// PrevMismatch => Nodes at the `position` have different previous references.
+    PrevMismatch {
+        /// Position of the nodes in the storages.
+        position: usize,
+    },
+    /// NextMismatch => Nodes at the `position` have different next references.
+    NextMismatch {
+        /// Position of the nodes in the storages.
+        position: usize,
+    },
+    /// EndsMismatch => Ends of the collections are different.
+    EndsMismatch,
+}
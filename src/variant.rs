@@ -1,14 +1,20 @@
-use crate::Refs;
+use crate::{NodeData, Refs};
 
 /// Variant defining `SelfRefCol` specifications.
 pub trait Variant: Sized {
     /// Elements of the collection.
     type Item;
 
+    /// The way the data of the nodes will be stored.
+    /// * `NodeDataLazyClose` if nodes can be closed, such as when elements are removed from the collection.
+    /// * `NodeDataEagerClose` if nodes are never closed, such as append-only collections.
+    type Storage: NodeData<Self::Item>;
+
     /// The way the previous node references will be stored.
     /// * `RefsNone` if there is no reference.
     /// * `RefsSingle` if there is zero or one reference.
     /// * `RefsArray` if there is a constant number of references.
+    /// * `RefsArrayLeftMost` if there is a bounded number of references kept at the left-most positions.
     /// * `RefsVec` if there is a dynamic number of references.
     type Prev: Refs;
 
@@ -16,6 +22,7 @@ pub trait Variant: Sized {
     /// * `RefsNone` if there is no reference.
     /// * `RefsSingle` if there is zero or one reference.
     /// * `RefsArray` if there is a constant number of references.
+    /// * `RefsArrayLeftMost` if there is a bounded number of references kept at the left-most positions.
     /// * `RefsVec` if there is a dynamic number of references.
     type Next: Refs;
 
@@ -24,6 +31,7 @@ pub trait Variant: Sized {
     /// * `RefsNone` if there is no reference.
     /// * `RefsSingle` if there is zero or one reference.
     /// * `RefsArray` if there is a constant number of references.
+    /// * `RefsArrayLeftMost` if there is a bounded number of references kept at the left-most positions.
     /// * `RefsVec` if there is a dynamic number of references.
     type Ends: Refs;
 }
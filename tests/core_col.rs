@@ -0,0 +1,811 @@
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+struct Singly<T>(PhantomData<T>);
+
+impl<T> Variant for Singly<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+struct Tree<T>(PhantomData<T>);
+
+impl<T> Variant for Tree<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsSingle<Self>;
+
+    type Next = RefsVec<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+type Core<T> = CoreCol<Singly<T>, SplitVec<Node<Singly<T>>, Recursive>>;
+type TreeCore<T> = CoreCol<Tree<T>, SplitVec<Node<Tree<T>>, Recursive>>;
+
+/// Creates a tree where the node with data `i` has the parent `parents[i]`.
+fn tree_with(parents: &[Option<usize>]) -> (TreeCore<usize>, Vec<NodePtr<Tree<usize>>>) {
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..parents.len()).map(|x| col.push(x)).collect();
+    for (child, parent) in parents.iter().enumerate() {
+        match parent {
+            Some(parent) => {
+                col.node_mut(&ptrs[*parent])
+                    .next_mut()
+                    .push(ptrs[child].clone());
+                col.node_mut(&ptrs[child])
+                    .prev_mut()
+                    .set_some(&ptrs[*parent]);
+            }
+            None => col.ends_mut().set_some(&ptrs[child]),
+        }
+    }
+    (col, ptrs)
+}
+
+fn core_with(
+    values: impl IntoIterator<Item = usize>,
+) -> (Core<usize>, Vec<NodePtr<Singly<usize>>>) {
+    let mut col = Core::new();
+    let ptrs = values.into_iter().map(|x| col.push(x)).collect();
+    (col, ptrs)
+}
+
+#[test]
+fn count_active_where() {
+    let (mut col, ptrs) = core_with(0..10);
+
+    assert_eq!(col.count_active_where(|x| x % 2 == 0), 5);
+    assert_eq!(col.count_active_where(|x| *x > 100), 0);
+    assert_eq!(col.count_active_where(|_| true), col.len());
+
+    col.close(&ptrs[4]);
+    col.close(&ptrs[5]);
+
+    assert_eq!(col.count_active_where(|x| x % 2 == 0), 4);
+    assert_eq!(col.count_active_where(|_| true), col.len());
+}
+
+#[test]
+fn fold_active() {
+    let (mut col, ptrs) = core_with(0..10);
+    assert_eq!(col.fold_active(0, |sum, x| sum + x), 45);
+
+    col.close(&ptrs[4]);
+    col.close(&ptrs[9]);
+    assert_eq!(col.fold_active(0, |sum, x| sum + x), 32);
+    assert_eq!(col.fold_active(0, |max, x| max.max(*x)), 8);
+
+    let joined = col.fold_active(String::new(), |acc, x| acc + &x.to_string());
+    assert_eq!(joined, "01235678");
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Pointer does not belong to the collection")]
+fn node_with_foreign_ptr_panics_in_debug() {
+    let (col, _) = core_with(0..3);
+    let (_other, other_ptrs) = core_with(0..3);
+    let _ = col.node(&other_ptrs[0]);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Pointer does not belong to the collection")]
+fn node_mut_with_foreign_ptr_panics_in_debug() {
+    let (mut col, _) = core_with(0..3);
+    let (_other, other_ptrs) = core_with(0..3);
+    let _ = col.node_mut(&other_ptrs[0]);
+}
+
+#[test]
+fn node_ptr_as_hash_map_key() {
+    let (mut col, ptrs) = core_with(0..5);
+    for i in 0..4 {
+        col.node_mut(&ptrs[i]).next_mut().set_some(&ptrs[i + 1]);
+    }
+
+    // memoize the distance of each node to the end of the chain
+    fn dist_to_end(
+        col: &Core<usize>,
+        ptr: &NodePtr<Singly<usize>>,
+        memo: &mut HashMap<NodePtr<Singly<usize>>, usize>,
+    ) -> usize {
+        if let Some(d) = memo.get(ptr) {
+            return *d;
+        }
+        let d = match col.node(ptr).next().get() {
+            Some(next) => 1 + dist_to_end(col, &next, memo),
+            None => 0,
+        };
+        memo.insert(ptr.clone(), d);
+        d
+    }
+
+    let mut memo = HashMap::new();
+    assert_eq!(dist_to_end(&col, &ptrs[0], &mut memo), 4);
+    assert_eq!(memo.len(), 5);
+    for (i, ptr) in ptrs.iter().enumerate() {
+        assert_eq!(memo.get(ptr), Some(&(4 - i)));
+    }
+
+    assert!(ptrs[2].ptr_eq(&col.node_ptr_at_pos(2)));
+    assert!(!ptrs[2].ptr_eq(&ptrs[3]));
+}
+
+#[test]
+fn into_active_data() {
+    let (mut col, ptrs) = core_with(0..6);
+    col.close(&ptrs[1]);
+    col.close(&ptrs[4]);
+    let len = col.len();
+
+    let data: Vec<_> = col.into_active_data().collect();
+    assert_eq!(data.len(), len);
+    assert_eq!(data, [0, 2, 3, 5]);
+}
+
+#[test]
+fn as_raw_parts() {
+    let (mut col, ptrs) = core_with(0..6);
+    col.ends_mut().set_some(&ptrs[2]);
+    col.close(&ptrs[4]);
+
+    let (nodes, ends, len) = col.as_raw_parts();
+    assert_eq!(nodes.len(), 6);
+    assert_eq!(len, 5);
+    assert_eq!(ends.get(), Some(ptrs[2].clone()));
+    let data: Vec<_> = (0..6).map(|i| nodes[i].data().copied()).collect();
+    assert_eq!(data, [Some(0), Some(1), Some(2), Some(3), None, Some(5)]);
+
+    let (nodes, ends, len) = col.into_inner();
+    assert_eq!(
+        (nodes.len(), ends.get(), len),
+        (6, Some(ptrs[2].clone()), 5)
+    );
+}
+
+#[test]
+fn out_degree_histogram() {
+    let empty = TreeCore::<usize>::new();
+    assert!(empty.out_degree_histogram().is_empty());
+
+    //       0
+    //    /  |  \
+    //   1   2   3
+    //  / \      |
+    // 4   5     6
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(0), Some(1), Some(1), Some(3)]);
+    assert_eq!(col.out_degree_histogram(), [4, 1, 1, 1]);
+
+    // closed nodes are not counted; while the reference of node 3 remains unless cleared
+    col.close(&ptrs[6]);
+    assert_eq!(col.out_degree_histogram(), [3, 1, 1, 1]);
+
+    col.node_mut(&ptrs[3]).next_mut().clear();
+    assert_eq!(col.out_degree_histogram(), [4, 0, 1, 1]);
+}
+
+#[test]
+fn get_node() {
+    let (mut col, _) = core_with(0..4);
+
+    assert_eq!(col.get_node(2).and_then(|x| x.data()), Some(&2));
+    assert!(col.get_node(4).is_none());
+
+    if let Some(node) = col.get_node_mut(1) {
+        *node.data_mut().expect("is active") = 10;
+    }
+    assert_eq!(col.get_node(1).and_then(|x| x.data()), Some(&10));
+    assert!(col.get_node_mut(4).is_none());
+}
+
+#[test]
+fn clone_with_data_list() {
+    let (mut col, ptrs) = core_with(0..5);
+    for (i, j) in [(3, 4), (4, 0), (0, 1)] {
+        col.node_mut(&ptrs[i]).next_mut().set_some(&ptrs[j]);
+    }
+    col.ends_mut().set_some(&ptrs[3]);
+    col.close(&ptrs[2]);
+
+    let doubled = col.clone_with_data(|x| 2 * x);
+    assert_eq!(doubled.len(), col.len());
+    assert_eq!(doubled.nodes().len(), col.nodes().len());
+
+    let list = |col: &Core<usize>| {
+        let mut values = vec![];
+        let mut current = col.ends().get();
+        while let Some(ptr) = current {
+            values.push(*col.node(&ptr).data().expect("is active"));
+            current = col.node(&ptr).next().get();
+        }
+        values
+    };
+    assert_eq!(list(&col), [3, 4, 0, 1]);
+    assert_eq!(list(&doubled), [6, 8, 0, 2]);
+
+    for pos in 0..col.nodes().len() {
+        let next = col.nodes()[pos].next().get().map(|x| col.position_of(&x));
+        let next_in_doubled = doubled.nodes()[pos]
+            .next()
+            .get()
+            .map(|x| doubled.position_of(&x));
+        assert_eq!(next, next_in_doubled);
+        assert_eq!(doubled.nodes()[pos].is_active(), pos != 2);
+    }
+}
+
+#[test]
+fn clone_with_data_tree() {
+    let (col, _) = tree_with(&[None, Some(0), Some(0), Some(1)]);
+    let cloned = col.clone_with_data(|x| x + 10);
+
+    let root = cloned.ends().get().expect("has a root");
+    assert_eq!(cloned.node(&root).data(), Some(&10));
+
+    let children: Vec<_> = (0..cloned.node(&root).next().len())
+        .filter_map(|i| cloned.node(&root).next().get(i))
+        .collect();
+    let data: Vec<_> = children.iter().map(|x| cloned.node(x).data()).collect();
+    assert_eq!(data, [Some(&11), Some(&12)]);
+
+    let parent = cloned.node(&children[0]).prev().get();
+    assert_eq!(parent, Some(root));
+    assert!(children.iter().all(|x| cloned.position_of(x).is_some()));
+}
+
+#[test]
+fn clone_with_data_drops_foreign_references() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0)]);
+    let (other, other_ptrs) = tree_with(&[None]);
+    col.node_mut(&ptrs[1])
+        .next_mut()
+        .push(other_ptrs[0].clone());
+    col.ends_mut().set_some(&other_ptrs[0]);
+
+    let cloned = col.clone_with_data(|x| *x);
+    drop(other);
+
+    let leaf = cloned.node_ptr_at_pos(1);
+    assert!(cloned.node(&leaf).next().is_empty());
+    assert_eq!(cloned.ends().get(), None);
+    assert_eq!(cloned.check(), Ok(()));
+
+    let root = cloned.node_ptr_at_pos(0);
+    assert_eq!(cloned.node(&root).next().get(0), Some(leaf.clone()));
+    assert_eq!(cloned.node(&leaf).prev().get(), Some(root));
+}
+
+#[test]
+fn next_and_prev_ptrs_of() {
+    let (col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1), Some(0)]);
+
+    assert_eq!(
+        col.next_ptrs_of(&ptrs[0]),
+        [ptrs[1].clone(), ptrs[2].clone(), ptrs[4].clone()]
+    );
+    assert_eq!(col.next_ptrs_of(&ptrs[1]), [ptrs[3].clone()]);
+    assert!(col.next_ptrs_of(&ptrs[3]).is_empty());
+
+    assert!(col.prev_ptrs_of(&ptrs[0]).is_empty());
+    assert_eq!(col.prev_ptrs_of(&ptrs[3]), [ptrs[1].clone()]);
+
+    let (col, ptrs) = core_with(0..2);
+    assert!(col.prev_ptrs_of(&ptrs[0]).is_empty());
+    assert!(col.next_ptrs_of(&ptrs[0]).is_empty());
+}
+
+#[test]
+fn path_between() {
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..7).map(|x| col.push(x)).collect();
+    // 0 -> 1 -> 3 -> 5
+    // 0 -> 2 -> 3
+    // 2 -> 4 -> 5
+    // 6 is disconnected
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (2, 4), (3, 5), (4, 5)] {
+        col.node_mut(&ptrs[from]).next_mut().push(ptrs[to].clone());
+    }
+
+    let data = |path: Vec<NodePtr<Tree<usize>>>| -> Vec<usize> {
+        path.iter().map(|x| *col.node(x).data().unwrap()).collect()
+    };
+
+    assert_eq!(
+        col.path_between(&ptrs[0], &ptrs[5]).map(data),
+        Some(vec![0, 1, 3, 5])
+    );
+    assert_eq!(
+        col.path_between(&ptrs[2], &ptrs[5]).map(data),
+        Some(vec![2, 3, 5])
+    );
+    assert_eq!(
+        col.path_between(&ptrs[4], &ptrs[4]).map(data),
+        Some(vec![4])
+    );
+
+    assert_eq!(col.path_between(&ptrs[5], &ptrs[0]), None);
+    assert_eq!(col.path_between(&ptrs[0], &ptrs[6]), None);
+    assert_eq!(col.path_between(&ptrs[6], &ptrs[1]), None);
+}
+
+#[test]
+fn topo_order() {
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..6).map(|x| col.push(x)).collect();
+    for (from, to) in [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)] {
+        col.node_mut(&ptrs[from]).next_mut().push(ptrs[to].clone());
+    }
+
+    let order = col.topo_order().expect("is acyclic");
+    assert_eq!(order.len(), 6);
+    let rank = |i: usize| order.iter().position(|x| x == &ptrs[i]).unwrap();
+    for (from, to) in [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)] {
+        assert!(rank(from) < rank(to));
+    }
+
+    col.close(&ptrs[4]);
+    assert_eq!(col.topo_order().map(|x| x.len()), Ok(5));
+}
+
+#[test]
+fn topo_order_with_cycle() {
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+    // 0 -> 1 -> 2 -> 3 -> 1, 3 -> 4
+    for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 1), (3, 4)] {
+        col.node_mut(&ptrs[from]).next_mut().push(ptrs[to].clone());
+    }
+
+    let cycle_nodes = col.topo_order().expect_err("has a cycle");
+    assert_eq!(cycle_nodes, &ptrs[1..]);
+}
+
+#[test]
+fn num_connected_components() {
+    let (mut col, ptrs) = core_with(0..7);
+    assert_eq!(col.num_connected_components(), 7);
+
+    // two independent lists: 0 -> 2 -> 4 -> 6 and 5 -> 3 -> 1
+    for (from, to) in [(0, 2), (2, 4), (4, 6), (5, 3), (3, 1)] {
+        col.node_mut(&ptrs[from]).next_mut().set_some(&ptrs[to]);
+    }
+    assert_eq!(col.num_connected_components(), 2);
+
+    col.node_mut(&ptrs[6]).next_mut().set_some(&ptrs[5]);
+    assert_eq!(col.num_connected_components(), 1);
+
+    let empty = Core::<usize>::new();
+    assert_eq!(empty.num_connected_components(), 0);
+}
+
+#[test]
+fn num_connected_components_forest() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), None, Some(3), Some(1)]);
+    assert_eq!(col.num_connected_components(), 2);
+
+    col.node_mut(&ptrs[0]).next_mut().clear();
+    col.node_mut(&ptrs[1]).prev_mut().set_none();
+    col.node_mut(&ptrs[2]).prev_mut().set_none();
+    assert_eq!(col.num_connected_components(), 4);
+}
+
+#[test]
+fn check_valid() {
+    let (col, _) = tree_with(&[None, Some(0), Some(0), Some(1)]);
+    assert_eq!(col.check(), Ok(()));
+
+    let (col, _) = core_with(0..3);
+    assert_eq!(col.check(), Ok(()));
+}
+
+#[test]
+fn check_len_mismatch() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1)]);
+    col.node_mut(&ptrs[3]).take_data();
+    assert_eq!(
+        col.check(),
+        Err(CollectionError::LenMismatch {
+            len: 4,
+            num_active_nodes: 3
+        })
+    );
+}
+
+#[test]
+fn check_dangling_reference() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1)]);
+    col.close(&ptrs[2]);
+    assert_eq!(
+        col.check(),
+        Err(CollectionError::DanglingReference { position: 0 })
+    );
+
+    let (mut col, ptrs) = tree_with(&[None, Some(0)]);
+    let (_other, other_ptrs) = tree_with(&[None]);
+    col.node_mut(&ptrs[1])
+        .next_mut()
+        .push(other_ptrs[0].clone());
+    assert_eq!(
+        col.check(),
+        Err(CollectionError::DanglingReference { position: 1 })
+    );
+}
+
+#[test]
+fn check_dangling_end() {
+    let (mut col, ptrs) = core_with(0..3);
+    col.ends_mut().set_some(&ptrs[1]);
+    col.close(&ptrs[1]);
+    assert_eq!(col.check(), Err(CollectionError::DanglingEnd));
+}
+
+#[test]
+fn for_each_active_data_mut() {
+    let (mut col, ptrs) = core_with(0..6);
+    col.close(&ptrs[2]);
+    col.close(&ptrs[5]);
+
+    let mut visited = vec![];
+    col.for_each_active_data_mut(|ptr, data| {
+        *data *= 10;
+        visited.push(ptr);
+    });
+
+    assert_eq!(visited.len(), col.len());
+    for ptr in &visited {
+        let pos = col.position_of(ptr).unwrap();
+        assert_eq!(col.node(ptr).data(), Some(&(10 * pos)));
+    }
+
+    for pair in visited.windows(2) {
+        col.node_mut(&pair[0]).next_mut().set_some(&pair[1]);
+    }
+    assert_eq!(col.node(&ptrs[1]).next().get(), Some(ptrs[3].clone()));
+}
+
+#[test]
+fn memory_footprint() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0)]);
+    let before = col.memory_footprint();
+    assert!(before >= col.nodes().capacity() * std::mem::size_of::<Node<Tree<usize>>>());
+
+    for _ in 0..100 {
+        let child = ptrs[1].clone();
+        col.node_mut(&ptrs[2]).next_mut().push(child);
+    }
+    let after = col.memory_footprint();
+    assert!(after >= before + 100 * std::mem::size_of::<NodePtr<Tree<usize>>>());
+}
+
+struct Forest<T>(PhantomData<T>);
+
+impl<T> Variant for Forest<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsSingle<Self>;
+
+    type Next = RefsVec<Self>;
+
+    type Ends = RefsArrayLeftMost<3, Self>;
+}
+
+#[test]
+fn push_and_remove_end() {
+    type ForestCore = CoreCol<Forest<usize>, SplitVec<Node<Forest<usize>>, Recursive>>;
+    let mut col = ForestCore::new();
+    let roots: Vec<_> = (0..3).map(|x| col.push(x)).collect();
+    for root in &roots {
+        col.push_end(root.clone());
+    }
+    assert!(col.ends().is_full());
+    let data = |col: &ForestCore| -> Vec<usize> {
+        col.ends()
+            .iter()
+            .map(|x| *col.node(&x).data().unwrap())
+            .collect()
+    };
+    assert_eq!(data(&col), [0, 1, 2]);
+
+    assert_eq!(col.remove_end(&roots[1]), Some(1));
+    assert_eq!(col.remove_end(&roots[1]), None);
+    assert_eq!(data(&col), [0, 2]);
+
+    col.push_end(roots[1].clone());
+    assert_eq!(data(&col), [0, 2, 1]);
+}
+
+#[test]
+fn remap_reference() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1)]);
+    let new = col.push(4);
+
+    col.remap_reference(&ptrs[1], &new);
+
+    assert_eq!(col.next_ptrs_of(&ptrs[0]), [new.clone(), ptrs[2].clone()]);
+    assert_eq!(col.node(&ptrs[3]).prev().get(), Some(new.clone()));
+    assert_eq!(col.ends().get(), Some(ptrs[0].clone()));
+
+    col.remap_reference(&ptrs[0], &new);
+    assert_eq!(col.ends().get(), Some(new.clone()));
+    assert_eq!(col.node(&ptrs[2]).prev().get(), Some(new));
+}
+
+#[test]
+fn shrink_references() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0)]);
+    for _ in 0..100 {
+        col.node_mut(&ptrs[0]).next_mut().push(ptrs[1].clone());
+    }
+    for _ in 0..99 {
+        col.node_mut(&ptrs[0]).next_mut().swap_remove(0);
+    }
+    let capacity = col.node(&ptrs[0]).next().capacity();
+    assert!(capacity >= 101);
+    let footprint = col.memory_footprint();
+
+    col.shrink_references();
+    assert_eq!(col.node(&ptrs[0]).next().len(), 2);
+    assert!(col.node(&ptrs[0]).next().capacity() < capacity);
+    assert!(col.memory_footprint() < footprint);
+}
+
+#[test]
+fn double_close_panic_keeps_len() {
+    let (mut col, ptrs) = core_with(0..3);
+    assert_eq!(col.close(&ptrs[1]), 1);
+    assert_eq!(col.len(), 2);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| col.close(&ptrs[1])));
+    assert!(result.is_err());
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn is_acyclic_next() {
+    let (mut col, ptrs) = core_with(0..4);
+    assert!(col.is_acyclic_next());
+
+    for i in 0..3 {
+        col.node_mut(&ptrs[i]).next_mut().set_some(&ptrs[i + 1]);
+    }
+    assert!(col.is_acyclic_next());
+
+    col.node_mut(&ptrs[3]).next_mut().set_some(&ptrs[1]);
+    assert!(!col.is_acyclic_next());
+
+    col.node_mut(&ptrs[3]).next_mut().set_some(&ptrs[3]);
+    assert!(!col.is_acyclic_next());
+
+    let (tree, _) = tree_with(&[None, Some(0), Some(0), Some(1), Some(1), Some(2)]);
+    assert!(tree.is_acyclic_next());
+}
+
+#[test]
+fn edges_as_data_pairs() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(2)]);
+    let mut pairs: Vec<_> = col
+        .edges_as_data_pairs()
+        .into_iter()
+        .map(|(a, b)| (*a, *b))
+        .collect();
+    pairs.sort();
+    assert_eq!(pairs, [(0, 1), (0, 2), (2, 3)]);
+
+    col.close(&ptrs[3]);
+    let pairs: Vec<_> = col
+        .edges_as_data_pairs()
+        .into_iter()
+        .map(|(a, b)| (*a, *b))
+        .collect();
+    assert_eq!(pairs, [(0, 1), (0, 2)]);
+}
+
+#[test]
+fn leaves_and_roots() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1), Some(1)]);
+    assert_eq!(
+        col.leaves(),
+        [ptrs[2].clone(), ptrs[3].clone(), ptrs[4].clone()]
+    );
+    assert_eq!(col.roots(), [ptrs[0].clone()]);
+
+    col.node_mut(&ptrs[1]).next_mut().clear();
+    col.close(&ptrs[3]);
+    col.close(&ptrs[4]);
+    assert_eq!(col.leaves(), [ptrs[1].clone(), ptrs[2].clone()]);
+    assert_eq!(col.roots(), [ptrs[0].clone()]);
+}
+
+#[test]
+fn redirect_edges() {
+    let (mut col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(1), Some(1)]);
+
+    // contract node 1 into node 2, and drop the edges into node 4
+    col.redirect_edges(|x| match x {
+        x if x == &ptrs[1] => Some(ptrs[2].clone()),
+        x if x == &ptrs[4] => None,
+        x => Some(x.clone()),
+    });
+
+    assert_eq!(
+        col.next_ptrs_of(&ptrs[0]),
+        [ptrs[2].clone(), ptrs[2].clone()]
+    );
+    assert_eq!(col.next_ptrs_of(&ptrs[1]), [ptrs[3].clone()]);
+    assert_eq!(col.node(&ptrs[3]).prev().get(), Some(ptrs[2].clone()));
+    assert_eq!(col.node(&ptrs[1]).prev().get(), Some(ptrs[0].clone()));
+    assert_eq!(col.node(&ptrs[4]).prev().get(), Some(ptrs[2].clone()));
+    assert_eq!(col.ends().get(), Some(ptrs[0].clone()));
+}
+
+#[test]
+fn new_with_fragment_hint() {
+    let mut col = Core::<usize>::new_with_fragment_hint(1000);
+    assert!(col.is_empty());
+    assert_eq!(col.nodes().fragments().len(), 1);
+    assert_eq!(col.nodes().fragments()[0].capacity(), 1000);
+
+    for x in 0..1000 {
+        col.push(x);
+    }
+    assert_eq!(col.nodes().fragments().len(), 1);
+    col.push(1000);
+    assert_eq!(col.nodes().fragments().len(), 2);
+
+    let mut col = Core::<usize>::new_with_fragment_hint(0);
+    col.push(0);
+    col.push(1);
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn try_push() {
+    let mut col = Core::<usize>::new();
+    for x in 0..100 {
+        let ptr = col.try_push(x).unwrap();
+        assert_eq!(col.node(&ptr).data(), Some(&x));
+    }
+    assert_eq!(col.len(), 100);
+
+    let pinned = orx_fixed_vec::FixedVec::<Node<Singly<usize>>>::new(2);
+    let mut col: SelfRefCol<Singly<usize>, MemoryReclaimNever, _> =
+        SelfRefCol::from_pinned_vec(pinned);
+    assert!(col.try_push(0).is_ok());
+    assert!(col.try_push(1).is_ok());
+    assert_eq!(col.try_push(2), Err(2));
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn depth_of() {
+    // 0 -> {1, 2}, 1 -> {3}, 3 -> {4}
+    let parents = [None, Some(0), Some(0), Some(1), Some(3)];
+    let mut col = TreeCore::<usize>::new();
+    let ptrs: Vec<_> = (0..parents.len()).map(|x| col.push(x)).collect();
+    for (child, parent) in parents.iter().enumerate() {
+        if let Some(p) = parent {
+            col.node_mut(&ptrs[*p]).next_mut().push(ptrs[child].clone());
+            col.node_mut(&ptrs[child]).prev_mut().set_some(&ptrs[*p]);
+        }
+    }
+
+    let depths: Vec<_> = ptrs.iter().map(|x| col.depth_of(x)).collect();
+    assert_eq!(depths, [Some(0), Some(1), Some(1), Some(2), Some(3)]);
+
+    col.close(&ptrs[2]);
+    assert_eq!(col.depth_of(&ptrs[2]), None);
+
+    col.node_mut(&ptrs[0]).prev_mut().set_some(&ptrs[4]);
+    assert_eq!(col.depth_of(&ptrs[4]), None);
+}
+
+#[test]
+fn in_degree() {
+    // DAG: 0 -> {1, 2}, 1 -> {3}, 2 -> {3}
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..4).map(|x| col.push(x)).collect();
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+        col.node_mut(&ptrs[from]).next_mut().push(ptrs[to].clone());
+    }
+
+    let degrees: Vec<_> = ptrs.iter().map(|x| col.in_degree(x)).collect();
+    assert_eq!(degrees, [0, 1, 1, 2]);
+
+    col.close(&ptrs[2]);
+    assert_eq!(col.in_degree(&ptrs[3]), 1);
+}
+
+#[test]
+fn dfs_from() {
+    // 0 -> {1, 2}, 1 -> {3}, 2 -> {3}, 3 -> {0, 4}
+    let mut col = TreeCore::new();
+    let ptrs: Vec<_> = (0..6).map(|x| col.push(x)).collect();
+    for (from, to) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 0), (3, 4)] {
+        col.node_mut(&ptrs[from]).next_mut().push(ptrs[to].clone());
+    }
+
+    let order = |col: &TreeCore<usize>, start: &NodePtr<Tree<usize>>| -> Vec<usize> {
+        col.dfs_from(start)
+            .map(|x| *col.node(&x).data().unwrap())
+            .collect()
+    };
+    assert_eq!(order(&col, &ptrs[0]), [0, 1, 3, 4, 2]);
+    assert_eq!(order(&col, &ptrs[3]), [3, 0, 1, 2, 4]);
+    assert_eq!(order(&col, &ptrs[5]), [5]);
+
+    col.close(&ptrs[1]);
+    assert_eq!(order(&col, &ptrs[0]), [0, 2, 3, 4]);
+    assert_eq!(col.dfs_from(&ptrs[1]).count(), 0);
+}
+
+#[test]
+fn node_references_next_and_prev() {
+    // 0 -> {1, 2, 3}, 1 -> {4}
+    let (col, ptrs) = tree_with(&[None, Some(0), Some(0), Some(0), Some(1)]);
+
+    let root = col.node(&ptrs[0]);
+    for child in &ptrs[1..4] {
+        assert!(root.references_next(child));
+        assert!(col.node(child).references_prev(&ptrs[0]));
+    }
+    assert!(!root.references_next(&ptrs[0]));
+    assert!(!root.references_next(&ptrs[4]));
+    assert!(!root.references_prev(&ptrs[1]));
+
+    assert!(col.node(&ptrs[1]).references_next(&ptrs[4]));
+    assert!(!col.node(&ptrs[4]).references_prev(&ptrs[0]));
+    assert!(!col.node(&ptrs[4]).references_next(&ptrs[1]));
+}
+
+#[test]
+fn try_move_node() {
+    let (mut col, ptrs) = core_with(0..5);
+    col.close(&ptrs[1]);
+    col.close(&ptrs[2]);
+
+    let out_of_bounds = MoveError::OutOfBounds {
+        position: 5,
+        len: 5,
+    };
+    assert_eq!(col.try_move_node(1, 5), Err(out_of_bounds));
+    assert_eq!(col.try_move_node(5, 3), Err(out_of_bounds));
+    assert_eq!(
+        col.try_move_node(3, 1),
+        Err(MoveError::NotForward {
+            closed: 3,
+            active: 1
+        })
+    );
+    assert_eq!(
+        col.try_move_node(1, 1),
+        Err(MoveError::NotForward {
+            closed: 1,
+            active: 1
+        })
+    );
+    assert_eq!(col.try_move_node(1, 2), Err(MoveError::SourceClosed));
+    assert_eq!(col.try_move_node(0, 3), Err(MoveError::DestinationActive));
+
+    let data =
+        |col: &Core<usize>| -> Vec<_> { (0..5).map(|i| col.nodes()[i].data().copied()).collect() };
+    assert_eq!(data(&col), [Some(0), None, None, Some(3), Some(4)]);
+
+    assert_eq!(col.try_move_node(1, 4), Ok(()));
+    assert_eq!(data(&col), [Some(0), Some(4), None, Some(3), None]);
+}
@@ -1,4 +1,4 @@
-use orx_pinned_vec::PinnedVec;
+use orx_pinned_vec::{Collection, PinnedVec};
 use orx_selfref_col::*;
 use orx_split_vec::{Recursive, SplitVec};
 use std::marker::PhantomData;
@@ -64,6 +64,8 @@ where
 impl<T> Variant for Doubly<T> {
     type Item = T;
 
+    type Storage = NodeDataLazyClose<T>;
+
     type Prev = RefsSingle<Self>;
 
     type Next = RefsSingle<Self>;
@@ -84,7 +86,7 @@ where
     let mut vec = vec![];
 
     if !col.is_empty() {
-        let [front, _] = front_back(&col);
+        let [front, _] = front_back(col);
         vec.push(front.data().unwrap().clone());
 
         let mut current = front;
@@ -107,7 +109,7 @@ where
     let mut vec = vec![];
 
     if !col.is_empty() {
-        let [_, back] = front_back(&col);
+        let [_, back] = front_back(col);
         vec.push(back.data().unwrap().clone());
 
         let mut current = back;
@@ -255,12 +257,12 @@ where
                 };
 
                 match prev {
-                    Some(ref prev) => col.node_mut(&prev).next_mut().set(next.clone()),
+                    Some(ref prev) => col.node_mut(prev).next_mut().set(next.clone()),
                     None => col.ends_mut().set(0, next.clone()),
                 }
 
                 match next {
-                    Some(ref next) => col.node_mut(&next).prev_mut().set(prev.clone()),
+                    Some(ref next) => col.node_mut(next).prev_mut().set(prev.clone()),
                     None => col.ends_mut().set(1, prev),
                 }
 
@@ -746,3 +748,610 @@ fn remove_at_test_abc() {
     assert_eq!(forward(&col), to_str(&[]));
     assert_eq!(nodes(&col), []);
 }
+
+#[test]
+fn front_back_data() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(col.front(), None);
+    assert_eq!(col.back(), None);
+
+    push_first(&mut col, 0.to_string());
+    assert_eq!(col.front(), Some(&0.to_string()));
+    assert_eq!(col.back(), Some(&0.to_string()));
+
+    push_front(&mut col, 1.to_string());
+    push_back(&mut col, 2.to_string());
+    assert_eq!(col.front(), Some(&1.to_string()));
+    assert_eq!(col.back(), Some(&2.to_string()));
+
+    pop_back(&mut col);
+    assert_eq!(col.back(), Some(&0.to_string()));
+}
+
+#[test]
+fn rotate_front() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..5 {
+        push_back(&mut col, i.to_string());
+    }
+
+    col.rotate_front(2);
+    assert_eq!(forward(&col), to_str(&[2, 3, 4, 0, 1]));
+    assert_eq!(backward(&col), to_str(&[1, 0, 4, 3, 2]));
+
+    col.rotate_front(7);
+    assert_eq!(forward(&col), to_str(&[4, 0, 1, 2, 3]));
+    assert_eq!(backward(&col), to_str(&[3, 2, 1, 0, 4]));
+
+    col.rotate_front(0);
+    assert_eq!(forward(&col), to_str(&[4, 0, 1, 2, 3]));
+
+    let mut single: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut single, 0.to_string());
+    single.rotate_front(3);
+    assert_eq!(forward(&single), to_str(&[0]));
+    assert_eq!(backward(&single), to_str(&[0]));
+}
+
+#[test]
+fn to_vec() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert!(col.to_vec().is_empty());
+
+    push_first(&mut col, 0.to_string());
+    for i in 1..4 {
+        push_back(&mut col, i.to_string());
+        push_front(&mut col, (10 + i).to_string());
+    }
+    assert_eq!(col.to_vec(), forward(&col));
+
+    pop_back(&mut col);
+    assert_eq!(col.to_vec(), to_str(&[13, 12, 11, 0, 1, 2]));
+    assert_eq!(col.to_vec(), forward(&col));
+}
+
+#[test]
+fn extract_if() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..10 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let odds = col.extract_if(|x| x.parse::<usize>().expect("is a number") % 2 == 1);
+    assert_eq!(odds, to_str(&[1, 3, 5, 7, 9]));
+    assert_eq!(forward(&col), to_str(&[0, 2, 4, 6, 8]));
+    assert_eq!(backward(&col), to_str(&[8, 6, 4, 2, 0]));
+    assert_eq!(col.len(), 5);
+
+    let ends = col.extract_if(|x| x == "0" || x == "8");
+    assert_eq!(ends, to_str(&[0, 8]));
+    assert_eq!(forward(&col), to_str(&[2, 4, 6]));
+    assert_eq!(backward(&col), to_str(&[6, 4, 2]));
+
+    let all = col.extract_if(|_| true);
+    assert_eq!(all, to_str(&[2, 4, 6]));
+    assert!(col.is_empty());
+}
+
+#[test]
+fn merge_sorted_by() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 1.to_string());
+    push_back(&mut col, 3.to_string());
+    push_back(&mut col, 5.to_string());
+
+    let mut other: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut other, 2.to_string());
+    push_back(&mut other, 4.to_string());
+    push_back(&mut other, 6.to_string());
+    let (other, _) = other.into_inner();
+
+    col.merge_sorted_by(other, |a, b| a.cmp(b));
+    assert_eq!(forward(&col), to_str(&[1, 2, 3, 4, 5, 6]));
+    assert_eq!(backward(&col), to_str(&[6, 5, 4, 3, 2, 1]));
+    assert_eq!(col.len(), 6);
+}
+
+#[test]
+fn iter_data_mut_from_front() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..4 {
+        push_back(&mut col, i.to_string());
+        push_front(&mut col, (10 + i).to_string());
+    }
+    assert_eq!(forward(&col), to_str(&[13, 12, 11, 0, 1, 2, 3]));
+
+    for (i, x) in col.iter_data_mut_from_front().enumerate() {
+        x.push_str(&format!("-{}", i));
+    }
+    let expected = ["13-0", "12-1", "11-2", "0-3", "1-4", "2-5", "3-6"];
+    assert_eq!(forward(&col), expected);
+    assert_eq!(col.to_vec(), expected);
+
+    let mut empty: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(empty.iter_data_mut_from_front().count(), 0);
+}
+
+#[test]
+fn iter_data_mut_from_front_stops_on_cycle() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    push_back(&mut col, 1.to_string());
+    push_back(&mut col, 2.to_string());
+
+    // the back links to the front building a cycle
+    let front = col.ends().front().unwrap();
+    let back = col.ends().back().unwrap();
+    col.node_mut(&back).next_mut().set_some(&front);
+
+    let visited: Vec<_> = col.iter_data_mut_from_front().map(|x| x.clone()).collect();
+    assert_eq!(visited, ["0", "1", "2"]);
+
+    // self loop
+    col.node_mut(&front).next_mut().set_some(&front);
+    assert_eq!(col.iter_data_mut_from_front().count(), 1);
+}
+
+#[test]
+fn swap_ends() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    col.swap_ends();
+    assert!(col.ends().is_empty());
+
+    push_first(&mut col, 0.to_string());
+    push_back(&mut col, 1.to_string());
+    push_back(&mut col, 2.to_string());
+
+    let [front, back] = [col.ends().get(0), col.ends().get(1)];
+    col.swap_ends();
+    assert_eq!(col.ends().get(0), back);
+    assert_eq!(col.ends().get(1), front);
+    assert_eq!(col.front(), Some(&2.to_string()));
+    assert_eq!(col.back(), Some(&0.to_string()));
+
+    col.swap_ends();
+    assert_eq!(col.ends().get(0), front);
+    assert_eq!(col.ends().get(1), back);
+}
+
+#[test]
+fn set_and_get_front_back_ptrs() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(col.ends().front(), None);
+    assert_eq!(col.ends().back(), None);
+
+    let ptrs: Vec<_> = (0..3).map(|x| col.push(x.to_string())).collect();
+    for i in 0..2 {
+        col.node_mut(&ptrs[i]).next_mut().set_some(&ptrs[i + 1]);
+        col.node_mut(&ptrs[i + 1]).prev_mut().set_some(&ptrs[i]);
+    }
+    col.ends_mut().set_front(Some(ptrs[0].clone()));
+    col.ends_mut().set_back(Some(ptrs[2].clone()));
+
+    assert_eq!(col.ends().front(), Some(ptrs[0].clone()));
+    assert_eq!(col.ends().back(), Some(ptrs[2].clone()));
+    assert_eq!(col.ends().get(0), col.ends().front());
+    assert_eq!(col.ends().get(1), col.ends().back());
+    assert_eq!(forward(&col), to_str(&[0, 1, 2]));
+    assert_eq!(backward(&col), to_str(&[2, 1, 0]));
+
+    col.ends_mut().set_back(None);
+    assert_eq!(col.ends().back(), None);
+    assert_eq!(col.ends().front(), Some(ptrs[0].clone()));
+    col.ends_mut().set_front(None);
+    assert!(col.ends().is_empty());
+}
+
+#[test]
+fn clone_structure_into() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..40 {
+        push_back(&mut col, i.to_string());
+    }
+    pop_front(&mut col);
+    pop_back(&mut col);
+
+    let mut dst: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut dst, "old".to_string());
+    let idx = dst.idx_at_position(0).unwrap();
+
+    col.clone_structure_into(&mut dst);
+    assert_eq!(dst.len(), col.len());
+    assert_eq!(forward(&dst), forward(&col));
+    assert_eq!(backward(&dst), backward(&col));
+    assert!(!dst.contains_idx(&idx));
+    let capacity = dst.nodes().capacity();
+
+    pop_front(&mut col);
+    push_back(&mut col, "new".to_string());
+    col.clone_structure_into(&mut dst);
+    assert_eq!(forward(&dst), forward(&col));
+    assert_eq!(backward(&dst), backward(&col));
+    assert_eq!(dst.nodes().capacity(), capacity);
+
+    let front = dst.ends().get(0).unwrap();
+    assert!(dst.position_of(&front).is_some());
+    assert!(col.position_of(&front).is_none());
+}
+
+#[test]
+fn clone_and_clone_from() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..3 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let clone = col.clone();
+    assert_eq!(forward(&clone), to_str(&[0, 1, 2]));
+    assert_eq!(backward(&clone), to_str(&[2, 1, 0]));
+    let front = clone.ends().get(0).unwrap();
+    assert!(col.position_of(&front).is_none());
+
+    let mut dst: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut dst, 0.to_string());
+    for i in 1..100 {
+        push_back(&mut dst, i.to_string());
+    }
+    let capacity = dst.nodes().capacity();
+
+    for _ in 0..10 {
+        dst.clone_from(&col);
+        assert_eq!(forward(&dst), to_str(&[0, 1, 2]));
+        assert_eq!(backward(&dst), to_str(&[2, 1, 0]));
+        assert!(dst.nodes().capacity() <= capacity);
+    }
+}
+
+#[test]
+fn detach() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..5 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let middle = get_at(&col, 2).unwrap();
+    let (prev, next) = col.node_mut(&middle).detach();
+    assert!(col.node(&middle).prev().is_empty());
+    assert!(col.node(&middle).next().is_empty());
+    assert_eq!(col.node(&middle).data(), Some(&2.to_string()));
+
+    let (prev, next) = (prev.get().unwrap(), next.get().unwrap());
+    col.node_mut(&prev).next_mut().set_some(&next);
+    col.node_mut(&next).prev_mut().set_some(&prev);
+    assert!(col.node(&middle).is_active());
+
+    assert_eq!(col.close_and_reclaim(&middle), 2.to_string());
+    assert_eq!(forward(&col), to_str(&[0, 1, 3, 4]));
+    assert_eq!(backward(&col), to_str(&[4, 3, 1, 0]));
+}
+
+#[test]
+fn end_node() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert!(col.end_node(0).is_none());
+    assert!(col.end_node(1).is_none());
+
+    push_first(&mut col, 1.to_string());
+    push_front(&mut col, 0.to_string());
+    push_back(&mut col, 2.to_string());
+
+    assert_eq!(col.end_node(0).and_then(|x| x.data()), Some(&0.to_string()));
+    assert_eq!(col.end_node(1).and_then(|x| x.data()), Some(&2.to_string()));
+    assert!(col.end_node(2).is_none());
+}
+
+#[test]
+fn diff() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..5 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let mut copy: Col<String, PolicyNever> = SelfRefCol::new();
+    col.clone_structure_into(&mut copy);
+    assert!(col.diff(&copy).is_empty());
+
+    let ptr = copy.node_ptr_at_pos(2);
+    copy.swap_data(&ptr, "changed".to_string());
+    assert_eq!(
+        col.diff(&copy),
+        [StructuralDiff::DataMismatch { position: 2 }]
+    );
+
+    pop_back(&mut copy);
+    let diffs = col.diff(&copy);
+    assert!(diffs.contains(&StructuralDiff::ActiveCountMismatch { left: 5, right: 4 }));
+    assert!(diffs.contains(&StructuralDiff::DataMismatch { position: 4 }));
+    assert!(diffs.contains(&StructuralDiff::NextMismatch { position: 3 }));
+    assert!(diffs.contains(&StructuralDiff::EndsMismatch));
+}
+
+#[test]
+fn cursor_walk() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..4 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let mut cursor = col.cursor_front();
+    let mut visited = vec![];
+    while let Some(x) = cursor.current() {
+        visited.push(x.clone());
+        cursor.move_next();
+    }
+    assert_eq!(visited, to_str(&[0, 1, 2, 3]));
+
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&3.to_string()));
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&2.to_string()));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current(), None);
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&0.to_string()));
+}
+
+#[test]
+fn cursor_mut_insert_and_remove() {
+    let mut col: Col<String, PolicyOnThreshold<2, String>> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..8 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let mut cursor = col.cursor_front_mut();
+    while let Some(x) = cursor.current() {
+        match x.parse::<usize>().unwrap() % 2 {
+            0 => {
+                cursor.remove_current();
+            }
+            _ => {
+                cursor.insert_after(format!("{}b", x));
+                cursor.move_next();
+                cursor.move_next();
+            }
+        }
+    }
+    cursor.insert_after("front".to_string());
+    cursor.move_prev();
+    *cursor.current_mut().unwrap() = "back".to_string();
+    drop(cursor);
+
+    let expected = ["front", "1", "1b", "3", "3b", "5", "5b", "7", "back"];
+    assert_eq!(forward(&col), expected);
+    assert_eq!(
+        backward(&col),
+        expected.iter().rev().copied().collect::<Vec<_>>()
+    );
+    assert_eq!(col.len(), 9);
+    assert_eq!(col.stats().num_reclaims, 1);
+}
+
+#[test]
+fn append() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for i in 1..1000 {
+        push_back(&mut col, i.to_string());
+    }
+
+    let mut other: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut other, 1000.to_string());
+    for i in 1001..2000 {
+        push_back(&mut other, i.to_string());
+    }
+    pop_back(&mut other);
+    let other_ptrs: Vec<_> = (0..other.nodes().len())
+        .filter(|x| other.nodes()[*x].is_active())
+        .map(|x| other.node_ptr_at_pos(x))
+        .collect();
+
+    col.append(other);
+
+    assert_eq!(col.len(), 1999);
+    let expected: Vec<_> = (0..1999).map(|x| x.to_string()).collect();
+    assert_eq!(forward(&col), expected);
+    assert_eq!(
+        backward(&col),
+        expected.iter().rev().cloned().collect::<Vec<_>>()
+    );
+    for (i, ptr) in other_ptrs.iter().enumerate() {
+        assert_eq!(col.node(ptr).data(), Some(&(1000 + i).to_string()));
+    }
+
+    let mut empty: Col<String, PolicyNever> = SelfRefCol::new();
+    empty.append(col);
+    assert_eq!(forward(&empty), expected);
+}
+
+#[test]
+fn partition() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push_first(&mut col, 0.to_string());
+    for x in 1..10 {
+        push_back(&mut col, x.to_string());
+    }
+
+    let (even, odd) = col.partition(|x| x.parse::<usize>().unwrap() % 2 == 0);
+
+    assert_eq!(even.len(), 5);
+    assert_eq!(forward(&even), to_str(&[0, 2, 4, 6, 8]));
+    assert_eq!(backward(&even), to_str(&[8, 6, 4, 2, 0]));
+
+    assert_eq!(odd.len(), 5);
+    assert_eq!(forward(&odd), to_str(&[1, 3, 5, 7, 9]));
+    assert_eq!(backward(&odd), to_str(&[9, 7, 5, 3, 1]));
+
+    let (all, none) = odd.partition(|_| true);
+    assert_eq!(forward(&all), to_str(&[1, 3, 5, 7, 9]));
+    assert!(none.is_empty());
+    assert!(forward(&none).is_empty());
+}
+
+#[test]
+fn num_ends() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(col.num_ends(), 0);
+
+    push_first(&mut col, 0.to_string());
+    assert_eq!(col.num_ends(), 2);
+
+    push_back(&mut col, 1.to_string());
+    assert_eq!(col.num_ends(), 2);
+
+    col.ends_mut().set(1, None);
+    assert_eq!(col.num_ends(), 1);
+
+    col.ends_mut().clear();
+    assert_eq!(col.num_ends(), 0);
+}
+
+#[test]
+fn link_sequentially() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    col.link_sequentially();
+    assert!(forward(&col).is_empty());
+    assert_eq!(col.num_ends(), 0);
+
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x.to_string())).collect();
+    col.link_sequentially();
+    assert_eq!(forward(&col), to_str(&[0, 1, 2, 3, 4]));
+    assert_eq!(backward(&col), to_str(&[4, 3, 2, 1, 0]));
+
+    col.close(&ptrs[0]);
+    col.close(&ptrs[2]);
+    col.link_sequentially();
+    assert_eq!(forward(&col), to_str(&[1, 3, 4]));
+    assert_eq!(backward(&col), to_str(&[4, 3, 1]));
+}
+
+#[test]
+fn swap_linked() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x.to_string())).collect();
+    col.link_sequentially();
+
+    let check = |col: &Col<String, PolicyNever>, expected: &[usize]| {
+        let reversed: Vec<_> = expected.iter().rev().copied().collect();
+        assert_eq!(forward(col), to_str(expected));
+        assert_eq!(backward(col), to_str(&reversed));
+    };
+
+    // adjacent
+    col.swap_linked(&ptrs[1], &ptrs[2]);
+    check(&col, &[0, 2, 1, 3, 4]);
+    col.swap_linked(&ptrs[1], &ptrs[2]);
+    check(&col, &[0, 1, 2, 3, 4]);
+
+    // non-adjacent
+    col.swap_linked(&ptrs[1], &ptrs[3]);
+    check(&col, &[0, 3, 2, 1, 4]);
+
+    // ends
+    col.swap_linked(&ptrs[4], &ptrs[0]);
+    check(&col, &[4, 3, 2, 1, 0]);
+    col.swap_linked(&ptrs[4], &ptrs[3]);
+    check(&col, &[3, 4, 2, 1, 0]);
+
+    // self
+    col.swap_linked(&ptrs[2], &ptrs[2]);
+    check(&col, &[3, 4, 2, 1, 0]);
+
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..2).map(|x| col.push(x.to_string())).collect();
+    col.link_sequentially();
+    col.swap_linked(&ptrs[1], &ptrs[0]);
+    check(&col, &[1, 0]);
+}
+
+#[test]
+fn reorder_by_prev_chain() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..8).map(|x| col.push(x.to_string())).collect();
+    col.link_sequentially();
+    col.swap_linked(&ptrs[1], &ptrs[6]);
+    col.swap_linked(&ptrs[2], &ptrs[3]);
+    let forward_before = forward(&col);
+    let backward_before = backward(&col);
+
+    // remove the node in the middle, leaving a hole in the storage
+    let (prev, next) = (
+        col.node(&ptrs[5]).prev().get(),
+        col.node(&ptrs[5]).next().get(),
+    );
+    col.node_mut(&prev.clone().unwrap())
+        .next_mut()
+        .set(next.clone());
+    col.node_mut(&next.unwrap()).prev_mut().set(prev);
+    col.close(&ptrs[5]);
+    let forward_before: Vec<_> = forward_before.into_iter().filter(|x| x != "5").collect();
+    let backward_before: Vec<_> = backward_before.into_iter().filter(|x| x != "5").collect();
+
+    let state = col.memory_state();
+    let idx = NodeIdx::new(state, &ptrs[0]);
+    assert!(col.reorder_by_prev_chain());
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(
+        col.try_get_ptr(&idx),
+        Err(NodeIdxError::ReorganizedCollection)
+    );
+    assert_eq!(col.stats().num_reclaims, 1);
+
+    assert_eq!(col.nodes().len(), 7);
+    assert_eq!(forward(&col), forward_before);
+    assert_eq!(backward(&col), backward_before);
+
+    let mut positions = vec![];
+    let mut current = col.ends().back();
+    while let Some(ptr) = current {
+        positions.push(col.position_of(&ptr).unwrap());
+        current = col.node(&ptr).prev().get();
+    }
+    assert_eq!(positions, (0..7).collect::<Vec<_>>());
+
+    let state = col.memory_state();
+    assert!(!col.reorder_by_prev_chain());
+    assert_eq!(col.memory_state(), state);
+    assert_eq!(backward(&col), backward_before);
+}
+
+#[test]
+fn insert_sorted_by() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let cmp = |a: &usize, b: &usize| a.cmp(b);
+    let values_backward = |col: &Col<usize, PolicyNever>| {
+        let mut values = vec![];
+        let mut current = col.ends().get(1);
+        while let Some(ptr) = current {
+            values.push(*col.node(&ptr).data().unwrap());
+            current = col.node(&ptr).prev().get();
+        }
+        values
+    };
+
+    let mut expected = vec![];
+    for x in [5, 1, 9, 5, 3, 0, 12, 7] {
+        let idx = col.insert_sorted_by(x, cmp);
+        assert_eq!(
+            col.try_get_ptr(&idx).map(|p| col.node(&p).data().copied()),
+            Ok(Some(x))
+        );
+
+        expected.push(x);
+        expected.sort();
+        let reversed: Vec<_> = expected.iter().rev().copied().collect();
+        assert_eq!(col.to_vec(), expected);
+        assert_eq!(col.back(), expected.last());
+        assert_eq!(values_backward(&col), reversed);
+    }
+    assert_eq!(col.len(), 8);
+}
@@ -0,0 +1,180 @@
+use orx_pinned_vec::{Collection, PinnedVec};
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+
+struct Pool<T>(PhantomData<T>);
+
+impl<T> Variant for Pool<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsNone;
+
+    type Ends = RefsNone;
+}
+
+type Core<T> = CoreCol<Pool<T>, SplitVec<Node<Pool<T>>, Recursive>>;
+
+/// Fills the holes from the front with the last active nodes having even data.
+#[derive(Clone, Default)]
+struct EvensFirstReclaimer;
+impl MemoryReclaimer<Pool<usize>> for EvensFirstReclaimer {
+    fn reclaim_nodes<P>(col: &mut CoreCol<Pool<usize>, P>) -> bool
+    where
+        P: PinnedVec<Node<Pool<usize>>>,
+    {
+        let mut nodes_moved = false;
+        let mut right_bound = col.nodes().len();
+
+        for vacant in 0..col.nodes().len() {
+            if col.nodes()[vacant].is_closed() {
+                let even = ((vacant + 1)..right_bound)
+                    .rev()
+                    .find(|&i| col.nodes()[i].data().is_some_and(|x| x % 2 == 0));
+
+                if let Some(occupied) = even {
+                    right_bound = occupied;
+                    col.move_node(vacant, occupied);
+                    nodes_moved = true;
+                }
+            }
+        }
+
+        nodes_moved
+    }
+}
+
+/// Moves the active nodes to the front while preserving their relative order.
+#[derive(Clone, Default)]
+struct OrderPreservingReclaimer;
+impl<T> MemoryReclaimer<Pool<T>> for OrderPreservingReclaimer {
+    fn reclaim_nodes<P>(col: &mut CoreCol<Pool<T>, P>) -> bool
+    where
+        P: PinnedVec<Node<Pool<T>>>,
+    {
+        let mut nodes_moved = false;
+        let mut vacant = 0;
+
+        for occupied in 0..col.nodes().len() {
+            if col.nodes()[occupied].is_active() {
+                if vacant < occupied {
+                    col.move_node(vacant, occupied);
+                    nodes_moved = true;
+                }
+                vacant += 1;
+            }
+        }
+
+        nodes_moved
+    }
+}
+
+fn layout(col: &Core<usize>) -> Vec<Option<usize>> {
+    col.nodes().iter().map(|x| x.data().copied()).collect()
+}
+
+#[test]
+fn then_runs_both_reclaimers_in_order() {
+    let mut col = Core::new();
+    let ptrs: Vec<_> = (0..8).map(|x| col.push(x)).collect();
+    for i in [1, 2, 5] {
+        col.close(&ptrs[i]);
+    }
+
+    let moved = Then::<EvensFirstReclaimer, OrderPreservingReclaimer>::reclaim_nodes(&mut col);
+    assert!(moved);
+    assert_eq!(
+        layout(&col),
+        [
+            Some(0),
+            Some(6),
+            Some(4),
+            Some(3),
+            Some(7),
+            None,
+            None,
+            None
+        ]
+    );
+}
+
+#[test]
+fn then_reports_no_move_when_neither_moves() {
+    let mut col = Core::new();
+    let ptrs: Vec<_> = (0..4).map(|x| col.push(x)).collect();
+    col.close(&ptrs[3]);
+
+    let moved = Then::<EvensFirstReclaimer, OrderPreservingReclaimer>::reclaim_nodes(&mut col);
+    assert!(!moved);
+    assert_eq!(layout(&col), [Some(0), Some(1), Some(2), None]);
+}
+
+fn num_closes_until_first_reclaim(shift: u32) -> usize {
+    num_closes_until_first_reclaim_with::<OrderPreservingReclaimer>(shift)
+}
+
+fn num_closes_until_first_reclaim_with<R: MemoryReclaimer<Pool<usize>>>(shift: u32) -> usize {
+    type Policy<R> = MemoryReclaimOnRuntimeThreshold<Pool<usize>, R>;
+    let mut col: SelfRefCol<Pool<usize>, Policy<R>, orx_fixed_vec::FixedVec<_>> =
+        SelfRefCol::with_capacity_and_policy(8, Policy::<R>::new(shift));
+    assert_eq!(col.memory().shift(), shift);
+    for x in 0..8 {
+        col.push(x);
+    }
+
+    for num_closes in 1..=8 {
+        let pos = col.active_positions().next().unwrap();
+        let ptr = col.node_ptr_at_pos(pos);
+        col.close_and_reclaim(&ptr);
+        if col.stats().num_reclaims > 0 {
+            return num_closes;
+        }
+    }
+    usize::MAX
+}
+
+#[test]
+fn runtime_threshold_reclaim_timings() {
+    // reclaims once closed nodes exceed used >> shift; i.e., 8 >> 2 = 2 and 8 >> 1 = 4
+    assert_eq!(num_closes_until_first_reclaim(2), 3);
+    assert_eq!(num_closes_until_first_reclaim(1), 5);
+    assert_eq!(num_closes_until_first_reclaim(0), usize::MAX);
+}
+
+#[test]
+fn runtime_threshold_with_then_reclaimer_honors_shift() {
+    type R = Then<EvensFirstReclaimer, OrderPreservingReclaimer>;
+    // 8 >> 1 = 4 and 8 >> 3 = 1; neither matches the default shift of 2 which would reclaim at 3
+    assert_eq!(num_closes_until_first_reclaim_with::<R>(1), 5);
+    assert_eq!(num_closes_until_first_reclaim_with::<R>(3), 2);
+    assert_eq!(num_closes_until_first_reclaim_with::<R>(0), usize::MAX);
+}
+
+#[test]
+fn clone_structure_into_keeps_policy_of_dst() {
+    type Policy = MemoryReclaimOnRuntimeThreshold<Pool<usize>, OrderPreservingReclaimer>;
+    type Col = SelfRefCol<Pool<usize>, Policy, SplitVec<Node<Pool<usize>>, Recursive>>;
+    let data = |col: &Col| -> Vec<usize> {
+        col.active_positions()
+            .map(|i| *col.nodes()[i].data().unwrap())
+            .collect()
+    };
+
+    let mut col = Col::with_capacity_and_policy(4, Policy::new(1));
+    for x in 0..3 {
+        col.push(x);
+    }
+
+    let mut dst = Col::with_capacity_and_policy(4, Policy::new(3));
+    col.clone_structure_into(&mut dst);
+    assert_eq!(data(&dst), [0, 1, 2]);
+    assert_eq!(dst.memory().shift(), 3);
+
+    dst.clone_from(&col);
+    assert_eq!(data(&dst), [0, 1, 2]);
+    assert_eq!(dst.memory().shift(), 1);
+}
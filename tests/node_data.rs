@@ -0,0 +1,129 @@
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+
+struct LazyList<T>(PhantomData<T>);
+
+impl<T> Variant for LazyList<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+struct EagerList<T>(PhantomData<T>);
+
+impl<T> Variant for EagerList<T> {
+    type Item = T;
+
+    type Storage = NodeDataEagerClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+type Col<V> = SelfRefCol<V, MemoryReclaimNever, SplitVec<Node<V>, Recursive>>;
+
+fn push_front<V>(col: &mut Col<V>, value: V::Item) -> NodePtr<V>
+where
+    V: Variant<Next = RefsSingle<V>, Ends = RefsSingle<V>>,
+{
+    let ptr = col.push(value);
+    if let Some(front) = col.ends().get() {
+        col.node_mut(&ptr).next_mut().set_some(&front);
+    }
+    col.ends_mut().set_some(&ptr);
+    ptr
+}
+
+fn to_vec<V>(col: &Col<V>) -> Vec<V::Item>
+where
+    V: Variant<Next = RefsSingle<V>, Ends = RefsSingle<V>>,
+    V::Item: Clone,
+{
+    let mut values = vec![];
+    let mut current = col.ends().get();
+    while let Some(ptr) = current {
+        let node = col.node(&ptr);
+        values.push(node.data().expect("active").clone());
+        current = node.next().get();
+    }
+    values
+}
+
+#[test]
+fn lazy_close_storage() {
+    let mut col: Col<LazyList<String>> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..3)
+        .map(|x| push_front(&mut col, x.to_string()))
+        .collect();
+    assert_eq!(to_vec(&col), ["2", "1", "0"]);
+
+    let front = col.node_mut(&ptrs[2]).next_mut().get();
+    col.ends_mut().set(front);
+    let removed = col.close(&ptrs[2]);
+    assert_eq!(removed, "2");
+    assert_eq!(to_vec(&col), ["1", "0"]);
+
+    let node = &col.nodes()[2];
+    assert!(node.is_closed());
+    assert_eq!(node.data(), None);
+    assert_eq!(col.len(), 2);
+    assert_eq!(col.nodes().len(), 3);
+}
+
+#[test]
+fn eager_close_storage() {
+    let mut col: Col<EagerList<String>> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..3)
+        .map(|x| push_front(&mut col, x.to_string()))
+        .collect();
+    assert_eq!(to_vec(&col), ["2", "1", "0"]);
+
+    let node = col.node_mut(&ptrs[1]);
+    assert!(node.is_active());
+    assert_eq!(node.swap_data("one".to_string()), "1");
+    node.data_mut().expect("active").push('!');
+    assert_eq!(to_vec(&col), ["2", "one!", "0"]);
+
+    // eager-close nodes cannot be closed, taking the data leaves the node intact
+    assert_eq!(col.node_mut(&ptrs[0]).take_data(), None);
+    assert!(col.node(&ptrs[0]).is_active());
+    assert_eq!(col.len(), 3);
+
+    let clone = col.clone();
+    assert_eq!(to_vec(&clone), ["2", "one!", "0"]);
+    assert_eq!(
+        format!("{:?}", clone.node(&clone.node_ptr_at_pos(0))),
+        "Node { data: Some(\"0\"), prev: RefsNone, next: RefsSingle(None) }"
+    );
+}
+
+#[test]
+#[should_panic(expected = "must be an open node with a closable storage")]
+fn eager_close_storage_cannot_close() {
+    let mut col: Col<EagerList<u32>> = SelfRefCol::new();
+    let ptr = push_front(&mut col, 42);
+    col.close(&ptr);
+}
+
+#[test]
+fn eager_close_storage_has_no_closed_state() {
+    assert!(size_of::<NodeDataEagerClose<u64>>() < size_of::<NodeDataLazyClose<u64>>());
+    assert!(size_of::<Node<EagerList<u64>>>() < size_of::<Node<LazyList<u64>>>());
+
+    let data = NodeDataEagerClose::active(7);
+    let clone = data.clone();
+    assert_eq!(clone.get(), Some(&7));
+    assert_eq!(format!("{:?}", clone), "NodeDataEagerClose(7)");
+    assert_eq!(data.into_option(), Some(7));
+}
@@ -0,0 +1,149 @@
+#![cfg(feature = "reclaim-hook")]
+
+use orx_pinned_vec::PinnedVec;
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+struct Pool<T>(PhantomData<T>);
+
+impl<T> Variant for Pool<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsNone;
+
+    type Ends = RefsNone;
+}
+
+#[derive(Clone, Default)]
+struct CompactReclaimer;
+impl<T> MemoryReclaimer<Pool<T>> for CompactReclaimer {
+    fn reclaim_nodes<P>(col: &mut CoreCol<Pool<T>, P>) -> bool
+    where
+        P: PinnedVec<Node<Pool<T>>>,
+    {
+        let mut nodes_moved = false;
+        let mut vacant = 0;
+
+        for occupied in 0..col.nodes().len() {
+            if col.nodes()[occupied].is_active() {
+                if vacant < occupied {
+                    col.move_node(vacant, occupied);
+                    nodes_moved = true;
+                }
+                vacant += 1;
+            }
+        }
+
+        nodes_moved
+    }
+}
+
+type Policy = MemoryReclaimOnThreshold<1, Pool<usize>, CompactReclaimer>;
+type Col = SelfRefCol<Pool<usize>, Policy, SplitVec<Node<Pool<usize>>, Recursive>>;
+
+#[test]
+fn on_reclaim_fires_with_moved_count() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let moved = Arc::new(AtomicUsize::new(0));
+
+    let mut col = Col::new();
+    let (c, m) = (calls.clone(), moved.clone());
+    col.on_reclaim(move |num_moved| {
+        c.fetch_add(1, Ordering::Relaxed);
+        m.fetch_add(num_moved, Ordering::Relaxed);
+    });
+
+    let ptrs: Vec<_> = (0..8).map(|x| col.push(x)).collect();
+
+    // removing the first three closes 3 of 8 nodes, which stays within the threshold of 8 / 2
+    for ptr in &ptrs[..3] {
+        col.close_and_reclaim(ptr);
+    }
+    assert_eq!(calls.load(Ordering::Relaxed), 0);
+
+    // the fifth removal exceeds the threshold, the remaining 3 nodes are moved to the front
+    let ptr = col.node_ptr_at_pos(3);
+    col.close_and_reclaim(&ptr);
+    let ptr = col.node_ptr_at_pos(4);
+    col.close_and_reclaim(&ptr);
+
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(moved.load(Ordering::Relaxed), 3);
+    assert_eq!(col.nodes().len(), 3);
+    assert_eq!(col.stats().num_nodes_moved_total, 3);
+}
+
+#[test]
+fn on_reclaim_keeps_collection_send() {
+    let moved = Arc::new(Mutex::new(vec![]));
+
+    let mut col = Col::new();
+    let m = moved.clone();
+    col.on_reclaim(move |num_moved| m.lock().expect("not poisoned").push(num_moved));
+
+    let col = std::thread::spawn(move || {
+        let ptrs: Vec<_> = (0..4).map(|x| col.push(x)).collect();
+        col.close_and_reclaim(&ptrs[0]);
+        col.close_and_reclaim(&ptrs[1]);
+        col.close_and_reclaim(&ptrs[2]);
+        col
+    })
+    .join()
+    .expect("no panic");
+
+    assert_eq!(*moved.lock().expect("not poisoned"), [1]);
+    assert_eq!(col.nodes().len(), 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn on_reclaim_fires_on_compact_and_remap() {
+    use std::collections::HashMap;
+
+    type Col = SelfRefCol<Pool<usize>, MemoryReclaimNever, SplitVec<Node<Pool<usize>>, Recursive>>;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let moved = Arc::new(AtomicUsize::new(0));
+
+    let mut col = Col::new();
+    let (c, m) = (calls.clone(), moved.clone());
+    col.on_reclaim(move |num_moved| {
+        c.fetch_add(1, Ordering::Relaxed);
+        m.fetch_add(num_moved, Ordering::Relaxed);
+    });
+
+    let ptrs: Vec<_> = (0..6).map(|x| col.push(x)).collect();
+    let mut handles: HashMap<_, _> = ptrs
+        .iter()
+        .enumerate()
+        .map(|(i, ptr)| (i, NodeIdx::new(col.memory_state(), ptr)))
+        .collect();
+
+    for ptr in &ptrs[..2] {
+        col.close(ptr);
+    }
+    col.compact_and_remap(&mut handles);
+
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(moved.load(Ordering::Relaxed), 2);
+    assert_eq!(col.stats().num_reclaims, 1);
+    assert_eq!(col.nodes().len(), 4);
+    for i in 2..6 {
+        assert_eq!(
+            col.node_from_idx(&handles[&i]).and_then(|x| x.data()),
+            Some(&i)
+        );
+    }
+
+    // nothing to reclaim
+    col.compact_and_remap(&mut handles);
+    assert_eq!(calls.load(Ordering::Relaxed), 1);
+    assert_eq!(col.stats().num_reclaims, 1);
+}
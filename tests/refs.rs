@@ -0,0 +1,393 @@
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+
+struct Tree<T>(PhantomData<T>);
+
+impl<T> Variant for Tree<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsSingle<Self>;
+
+    type Next = RefsVec<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+type Core<T> = CoreCol<Tree<T>, SplitVec<Node<Tree<T>>, Recursive>>;
+
+fn core_with(values: impl IntoIterator<Item = usize>) -> (Core<usize>, Vec<NodePtr<Tree<usize>>>) {
+    let mut col = Core::new();
+    let ptrs = values.into_iter().map(|x| col.push(x)).collect();
+    (col, ptrs)
+}
+
+#[test]
+fn refs_len() {
+    let (_col, ptrs) = core_with(0..3);
+
+    assert_eq!(RefsNone::empty().len(), 0);
+
+    let mut single = RefsSingle::<Tree<usize>>::empty();
+    assert_eq!(single.len(), 0);
+    single.set_some(&ptrs[0]);
+    assert_eq!(single.len(), 1);
+
+    let vec = RefsVec::<Tree<usize>>::empty();
+    assert_eq!(vec.len(), 0);
+}
+
+#[test]
+fn refs_array_len_counts_present_with_gaps() {
+    let (_col, ptrs) = core_with(0..3);
+
+    let mut array = RefsArray::<4, Tree<usize>>::empty();
+    assert_eq!(array.len(), 0);
+
+    array.set_some(1, &ptrs[0]);
+    array.set_some(3, &ptrs[2]);
+    assert_eq!(array.len(), 2);
+    assert!(!array.is_empty());
+
+    array.set_none(1);
+    assert_eq!(array.len(), 1);
+}
+
+#[test]
+fn refs_vec_clear_and_free() {
+    let (_col, ptrs) = core_with(0..100);
+
+    let mut vec = RefsVec::<Tree<usize>>::empty();
+    for ptr in &ptrs {
+        vec.push(ptr.clone());
+    }
+    assert_eq!(vec.len(), 100);
+    assert_eq!(vec.get(42), Some(ptrs[42].clone()));
+
+    vec.clear();
+    assert!(vec.is_empty());
+    assert!(vec.capacity() >= 100);
+
+    for ptr in &ptrs {
+        vec.push(ptr.clone());
+    }
+    vec.clear_and_free();
+    assert!(vec.is_empty());
+    assert_eq!(vec.capacity(), 0);
+}
+
+#[test]
+fn refs_array_left_most() {
+    let (_col, ptrs) = core_with(0..4);
+
+    let mut array = RefsArrayLeftMost::<4, Tree<usize>>::empty();
+    assert!(array.is_empty());
+    for ptr in ptrs.iter().take(3) {
+        array.push(ptr.clone());
+    }
+    assert_eq!(array.len(), 3);
+    assert!(!array.is_full());
+    assert_eq!(array.iter().collect::<Vec<_>>(), &ptrs[..3]);
+
+    assert_eq!(array.remove(&ptrs[1]), Some(1));
+    assert_eq!(array.remove(&ptrs[3]), None);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.get(0), Some(ptrs[0].clone()));
+    assert_eq!(array.get(1), Some(ptrs[2].clone()));
+    assert_eq!(array.get(2), None);
+
+    assert_eq!(array.remove_at(0), Some(ptrs[0].clone()));
+    assert_eq!(array.iter().collect::<Vec<_>>(), [ptrs[2].clone()]);
+}
+
+#[test]
+fn refs_array_left_most_present_slice() {
+    let (_col, ptrs) = core_with(0..3);
+
+    let mut array = RefsArrayLeftMost::<8, Tree<usize>>::empty();
+    assert!(array.present_slice().is_empty());
+
+    for ptr in &ptrs {
+        array.push(ptr.clone());
+    }
+    let slice = array.present_slice();
+    assert_eq!(slice.len(), array.len());
+    assert!(slice.iter().all(|x| x.is_some()));
+
+    array.remove(&ptrs[0]);
+    let slice = array.present_slice();
+    assert_eq!(slice.len(), 2);
+    assert_eq!(slice, [Some(ptrs[1].clone()), Some(ptrs[2].clone())]);
+}
+
+#[test]
+fn refs_vec_swap_remove() {
+    let (_col, ptrs) = core_with(0..5);
+
+    let mut vec = RefsVec::<Tree<usize>>::empty();
+    for ptr in &ptrs {
+        vec.push(ptr.clone());
+    }
+
+    let removed = vec.swap_remove(1);
+    assert_eq!(removed, ptrs[1]);
+    assert_eq!(vec.len(), 4);
+    assert_eq!(vec.get(1), Some(ptrs[4].clone()));
+
+    let removed = vec.swap_remove_ptr(&ptrs[0]);
+    assert_eq!(removed, Some(ptrs[0].clone()));
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec.get(0), Some(ptrs[3].clone()));
+
+    assert_eq!(vec.swap_remove_ptr(&ptrs[0]), None);
+    assert_eq!(vec.len(), 3);
+
+    let removed = vec.swap_remove(2);
+    assert_eq!(removed, ptrs[2]);
+    assert_eq!(vec.len(), 2);
+}
+
+#[test]
+fn refs_array_clear_from() {
+    let (_col, ptrs) = core_with(0..3);
+
+    let mut array = RefsArray::<3, Tree<usize>>::empty();
+    for (i, ptr) in ptrs.iter().enumerate() {
+        array.set_some(i, ptr);
+    }
+
+    array.clear_from(1);
+    assert_eq!(array.len(), 1);
+    assert_eq!(array.get(0), Some(ptrs[0].clone()));
+    assert_eq!(array.get(1), None);
+    assert_eq!(array.get(2), None);
+
+    array.clear_from(3);
+    assert_eq!(array.len(), 1);
+}
+
+#[test]
+fn refs_array_map() {
+    let (_col, ptrs) = core_with(0..2);
+
+    let mut ends = RefsArray::<2, Tree<usize>>::empty();
+    ends.set_some(0, &ptrs[0]);
+    ends.set_some(1, &ptrs[1]);
+
+    let mut slot = 2;
+    let reversed = ends.map(|_| {
+        slot -= 1;
+        ends.get(slot)
+    });
+    assert_eq!(reversed.get(0), Some(ptrs[1].clone()));
+    assert_eq!(reversed.get(1), Some(ptrs[0].clone()));
+
+    let cleared = ends.map(|_| None);
+    assert!(cleared.is_empty());
+    assert_eq!(ends.len(), 2);
+}
+
+#[test]
+fn refs_vec_insert_sorted_by() {
+    let (col, ptrs) = core_with([10, 20, 30, 40, 25, 5, 50, 20]);
+    let key = |ptr: &NodePtr<Tree<usize>>| *col.node(ptr).data().expect("is active");
+
+    let mut vec = RefsVec::<Tree<usize>>::empty();
+    for ptr in &ptrs[..4] {
+        vec.push(ptr.clone());
+    }
+
+    let insert = |vec: &mut RefsVec<Tree<usize>>, ptr: &NodePtr<Tree<usize>>| {
+        let value = key(ptr);
+        vec.insert_sorted_by(ptr.clone(), |x| key(x).cmp(&value))
+    };
+
+    assert_eq!(insert(&mut vec, &ptrs[4]), 2);
+    assert_eq!(insert(&mut vec, &ptrs[5]), 0);
+    assert_eq!(insert(&mut vec, &ptrs[6]), 6);
+    assert_eq!(insert(&mut vec, &ptrs[7]), 3);
+
+    let values: Vec<_> = (0..vec.len())
+        .filter_map(|i| vec.get(i))
+        .map(|x| key(&x))
+        .collect();
+    assert_eq!(values, [5, 10, 20, 20, 25, 30, 40, 50]);
+    assert_eq!(vec.get(2), Some(ptrs[1].clone()));
+    assert_eq!(vec.get(3), Some(ptrs[7].clone()));
+}
+
+#[test]
+fn refs_array_left_most_remove_within_len() {
+    let (_col, ptrs) = core_with(0..4);
+
+    let mut array = RefsArrayLeftMost::<6, Tree<usize>>::empty();
+    for ptr in &ptrs[..3] {
+        array.push(ptr.clone());
+    }
+
+    assert_eq!(array.remove(&ptrs[3]), None);
+    assert_eq!(array.remove(&ptrs[2]), Some(2));
+    assert_eq!(array.remove(&ptrs[2]), None);
+    assert_eq!(array.len(), 2);
+
+    assert_eq!(array.remove(&ptrs[0]), Some(0));
+    assert_eq!(array.remove(&ptrs[1]), Some(0));
+    assert!(array.is_empty());
+    assert_eq!(array.remove(&ptrs[1]), None);
+    assert!((0..6).all(|i| array.get(i).is_none()));
+}
+
+#[test]
+fn refs_vec_any_all() {
+    let (mut col, ptrs) = core_with(0..4);
+    col.node_mut(&ptrs[1]).next_mut().push(ptrs[3].clone());
+
+    let mut children = RefsVec::<Tree<usize>>::empty();
+    assert!(!children.any(|_| true));
+    assert!(children.all(|_| false));
+
+    children.push(ptrs[1].clone());
+    children.push(ptrs[2].clone());
+
+    let is_leaf = |x: &NodePtr<Tree<usize>>| col.node(x).next().is_empty();
+    assert!(children.any(is_leaf));
+    assert!(!children.all(is_leaf));
+
+    let is_even = |x: &NodePtr<Tree<usize>>| col.node(x).data().is_some_and(|d| d % 2 == 0);
+    assert!(children.any(is_even));
+    assert!(children.all(|x| col.node(x).is_active()));
+}
+
+#[test]
+fn refs_single_from_node_ptr() {
+    let (_col, ptrs) = core_with(0..2);
+
+    let single: RefsSingle<Tree<usize>> = ptrs[1].clone().into();
+    assert_eq!(single.get(), Some(ptrs[1].clone()));
+
+    let single: RefsSingle<Tree<usize>> = Some(ptrs[0].clone()).into();
+    assert_eq!(single.get(), Some(ptrs[0].clone()));
+
+    let single = RefsSingle::<Tree<usize>>::from(None);
+    assert!(single.is_empty());
+}
+
+#[test]
+fn refs_vec_iter_active() {
+    let mut col: SelfRefCol<
+        Tree<usize>,
+        MemoryReclaimNever,
+        SplitVec<Node<Tree<usize>>, Recursive>,
+    > = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..4).map(|x| col.push(x)).collect();
+
+    let mut children = RefsVec::<Tree<usize>>::empty();
+    for ptr in &ptrs[1..] {
+        children.push(ptr.clone());
+    }
+
+    col.close_and_reclaim(&ptrs[2]);
+
+    let active: Vec<_> = children
+        .iter_active(&col)
+        .map(|x| *x.data().unwrap())
+        .collect();
+    assert_eq!(active, [1, 3]);
+    assert_eq!(children.len(), 3);
+}
+
+#[test]
+fn refs_array_left_most_binary_search_by() {
+    let (col, ptrs) = core_with([0, 10, 20, 30]);
+
+    let mut array = RefsArrayLeftMost::<5, Tree<usize>>::empty();
+    for ptr in &ptrs {
+        array.push(ptr.clone());
+    }
+
+    let search = |key: usize| array.binary_search_by(|x| col.node(x).data().unwrap().cmp(&key));
+    assert_eq!(search(0), Ok(0));
+    assert_eq!(search(20), Ok(2));
+    assert_eq!(search(30), Ok(3));
+    assert_eq!(search(15), Err(2));
+    assert_eq!(search(40), Err(4));
+
+    let empty = RefsArrayLeftMost::<5, Tree<usize>>::empty();
+    assert_eq!(empty.binary_search_by(|_| std::cmp::Ordering::Less), Err(0));
+}
+
+#[test]
+fn refs_vec_remap() {
+    let (col, ptrs) = core_with(0..6);
+
+    let mut vec = RefsVec::<Tree<usize>>::empty();
+    for ptr in &ptrs[..3] {
+        vec.push(ptr.clone());
+    }
+
+    // moves each reference three positions ahead
+    let position = |ptr: &NodePtr<Tree<usize>>| ptrs.iter().position(|x| x == ptr).unwrap();
+    vec.remap(|x| ptrs[position(x) + 3].clone());
+
+    let values: Vec<_> = vec
+        .iter_ptr()
+        .map(|x| *col.node(x).data().unwrap())
+        .collect();
+    assert_eq!(values, [3, 4, 5]);
+    assert_eq!(vec.len(), 3);
+}
+
+#[test]
+fn refs_array_iter_with_gaps() {
+    let (_col, ptrs) = core_with(0..6);
+
+    let mut array = RefsArray::<6, Tree<usize>>::empty();
+    assert_eq!(array.iter().count(), 0);
+    assert_eq!(array.iter().next_back(), None);
+
+    for i in [1, 2, 4] {
+        array.set_some(i, &ptrs[i]);
+    }
+
+    let iter = array.iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [ptrs[1].clone(), ptrs[2].clone(), ptrs[4].clone()]
+    );
+    assert_eq!(
+        array.iter().rev().collect::<Vec<_>>(),
+        [ptrs[4].clone(), ptrs[2].clone(), ptrs[1].clone()]
+    );
+
+    let mut iter = array.iter();
+    assert_eq!(iter.next(), Some(ptrs[1].clone()));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(ptrs[4].clone()));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(ptrs[2].clone()));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn refs_array_iter_mut_with_gaps() {
+    let (_col, ptrs) = core_with(0..6);
+
+    let mut array = RefsArray::<6, Tree<usize>>::empty();
+    for i in [0, 3, 5] {
+        array.set_some(i, &ptrs[i]);
+    }
+    assert_eq!(array.iter_mut().len(), 3);
+
+    for (ptr, new) in array.iter_mut().rev().zip([2, 1]) {
+        *ptr = ptrs[new].clone();
+    }
+    assert_eq!(array.get(0), Some(ptrs[0].clone()));
+    assert_eq!(array.get(3), Some(ptrs[1].clone()));
+    assert_eq!(array.get(5), Some(ptrs[2].clone()));
+    assert_eq!(array.get(1), None);
+}
@@ -0,0 +1,923 @@
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::{Collection, PinnedVec};
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+
+struct Pool<T>(PhantomData<T>);
+
+impl<T> Variant for Pool<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsNone;
+
+    type Ends = RefsNone;
+}
+
+type PolicyNever = MemoryReclaimNever;
+type PolicyOnThreshold<const D: usize, T> = MemoryReclaimOnThreshold<D, Pool<T>, CompactReclaimer>;
+
+#[derive(Clone, Default)]
+struct CompactReclaimer;
+impl<T> MemoryReclaimer<Pool<T>> for CompactReclaimer {
+    fn reclaim_nodes<P>(col: &mut CoreCol<Pool<T>, P>) -> bool
+    where
+        P: PinnedVec<Node<Pool<T>>>,
+    {
+        let mut nodes_moved = false;
+        let mut vacant = 0;
+
+        for occupied in 0..col.nodes().len() {
+            if col.nodes()[occupied].is_active() {
+                if vacant < occupied {
+                    col.move_node(vacant, occupied);
+                    nodes_moved = true;
+                }
+                vacant += 1;
+            }
+        }
+
+        nodes_moved
+    }
+}
+
+type Col<T, M> = SelfRefCol<Pool<T>, M, SplitVec<Node<Pool<T>>, Recursive>>;
+
+fn push<T, M>(col: &mut Col<T, M>, value: T) -> NodeIdx<Pool<T>>
+where
+    M: MemoryPolicy<Pool<T>>,
+{
+    let ptr = col.push(value);
+    NodeIdx::new(col.memory_state(), &ptr)
+}
+
+#[test]
+fn data_many_mut() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x)).collect();
+
+    let [a, b, c] = col.data_many_mut([&idx[4], &idx[0], &idx[2]]).unwrap();
+    *a += 40;
+    *b += 100;
+    std::mem::swap(b, c);
+
+    let data: Vec<_> = col.nodes().iter().map(|x| *x.data().unwrap()).collect();
+    assert_eq!(data, [2, 1, 100, 3, 44]);
+}
+
+#[test]
+fn data_many_mut_duplicate_or_invalid() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x)).collect();
+
+    assert!(col.data_many_mut([&idx[1], &idx[3], &idx[1]]).is_none());
+
+    let ptr = col.try_get_ptr(&idx[3]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert!(col.data_many_mut([&idx[1], &idx[3]]).is_none());
+
+    let mut other: Col<usize, PolicyNever> = SelfRefCol::new();
+    let other_idx = push(&mut other, 42);
+    assert!(col.data_many_mut([&idx[1], &other_idx]).is_none());
+
+    assert!(col.data_many_mut([&idx[1], &idx[2]]).is_some());
+}
+
+#[test]
+fn resolve_in_hot_loop() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..1000).map(|x| push(&mut col, x)).collect();
+
+    let resolved = col.resolve(&idx[999]).unwrap();
+    assert_eq!(resolved.ptr(), col.try_get_ptr(&idx[999]).unwrap());
+    let mut sum = 0;
+    for _ in 0..100_000 {
+        sum += *resolved.data();
+    }
+    assert_eq!(sum, 999 * 100_000);
+
+    let mut resolved = col.resolve_mut(&idx[7]).unwrap();
+    for _ in 0..100_000 {
+        *resolved.data_mut() += 1;
+    }
+    assert_eq!(*resolved.data(), 100_007);
+
+    let ptr = col.try_get_ptr(&idx[7]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert!(col.resolve(&idx[7]).is_none());
+    assert!(col.resolve_mut(&idx[7]).is_none());
+}
+
+#[test]
+fn data_disjoint_mut() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+
+    let [a, b, c] = col
+        .data_disjoint_mut([&ptrs[4], &ptrs[0], &ptrs[2]])
+        .unwrap();
+    *a += 40;
+    *b += 100;
+    std::mem::swap(b, c);
+
+    let data: Vec<_> = col.nodes().iter().map(|x| *x.data().unwrap()).collect();
+    assert_eq!(data, [2, 1, 100, 3, 44]);
+
+    assert!(col
+        .data_disjoint_mut([&ptrs[1], &ptrs[3], &ptrs[1]])
+        .is_none());
+
+    col.close(&ptrs[3]);
+    assert!(col.data_disjoint_mut([&ptrs[1], &ptrs[3]]).is_none());
+
+    let mut other: Col<usize, PolicyNever> = SelfRefCol::new();
+    let other_ptr = other.push(42);
+    assert!(col.data_disjoint_mut([&ptrs[1], &other_ptr]).is_none());
+}
+
+#[test]
+fn swap_data_by_idx() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..3).map(|x| push(&mut col, x.to_string())).collect();
+
+    let old = col.swap_data_by_idx(&idx[1], 42.to_string());
+    assert_eq!(old, Ok(1.to_string()));
+    assert_eq!(
+        col.node_from_idx(&idx[1]).unwrap().data(),
+        Some(&42.to_string())
+    );
+
+    let ptr = col.try_get_ptr(&idx[2]).unwrap();
+    col.close_and_reclaim(&ptr);
+    let result = col.swap_data_by_idx(&idx[2], 7.to_string());
+    assert_eq!(result, Err(NodeIdxError::RemovedNode));
+}
+
+#[test]
+fn swap_data_by_idx_reorganized() {
+    let mut col: Col<String, PolicyOnThreshold<1, String>> = SelfRefCol::new();
+    let idx: Vec<_> = (0..4).map(|x| push(&mut col, x.to_string())).collect();
+
+    for i in [0, 1, 2] {
+        let ptr = col.try_get_ptr(&idx[i]).unwrap();
+        col.close_and_reclaim(&ptr);
+    }
+    assert_eq!(col.len(), 1);
+    assert_eq!(col.nodes().len(), 1);
+
+    // node 3 is moved to position 0 where idx[0] points to; yet, the state has changed
+    let result = col.swap_data_by_idx(&idx[0], 7.to_string());
+    assert_eq!(result, Err(NodeIdxError::ReorganizedCollection));
+
+    let result = col.swap_data_by_idx(&idx[3], 7.to_string());
+    assert_eq!(result, Err(NodeIdxError::OutOfBounds));
+}
+
+#[test]
+fn from_iter_and_extend() {
+    let mut col: Col<usize, PolicyNever> = (0..100).collect();
+    assert_eq!(col.len(), 100);
+    assert!(col
+        .nodes()
+        .iter()
+        .all(|x| x.prev().is_empty() && x.next().is_empty()));
+
+    col.extend(100..150);
+    assert_eq!(col.len(), 150);
+
+    let data: Vec<_> = col.nodes().iter().map(|x| *x.data().unwrap()).collect();
+    assert_eq!(data, (0..150).collect::<Vec<_>>());
+}
+
+#[test]
+fn stats_after_grow_shrink_cycle() {
+    let mut col: Col<usize, PolicyOnThreshold<1, usize>> = SelfRefCol::new();
+    assert_eq!(col.stats(), Stats::default());
+
+    let idx: Vec<_> = (0..100).map(|x| push(&mut col, x)).collect();
+    let stats = col.stats();
+    assert!(stats.num_growths > 0);
+    assert_eq!(stats.num_reclaims, 0);
+    assert_eq!(stats.num_nodes_moved_total, 0);
+    assert_eq!(stats.peak_storage_len, 100);
+
+    for i in (0..100).filter(|x| x % 4 != 0) {
+        if let Ok(ptr) = col.try_get_ptr(&idx[i]) {
+            col.close_and_reclaim(&ptr);
+        }
+    }
+
+    let stats = col.stats();
+    assert!(stats.num_reclaims > 0);
+    assert!(stats.num_nodes_moved_total > 0);
+    assert!(stats.num_nodes_moved_total <= 100);
+    assert_eq!(stats.peak_storage_len, 100);
+    assert!(col.nodes().len() < 100);
+}
+
+#[test]
+fn from_pinned_vec_with_preset_capacity() {
+    let pinned = FixedVec::<Node<Pool<usize>>>::new(50);
+    let mut col: SelfRefCol<Pool<usize>, PolicyNever, _> = SelfRefCol::from_pinned_vec(pinned);
+
+    assert!(col.is_empty());
+    assert_eq!(col.utilization().capacity, 50);
+    assert_eq!(col.memory_state(), MemoryState::default());
+
+    for i in 0..50 {
+        col.push(i);
+    }
+    assert_eq!(col.len(), 50);
+    assert_eq!(col.utilization().capacity, 50);
+}
+
+#[test]
+fn from_pinned_vec_counts_active_nodes() {
+    let mut pinned = SplitVec::with_recursive_growth();
+    for i in 0..4 {
+        pinned.push(Node::new_free_node(i));
+    }
+    pinned[1].close();
+
+    let col: Col<usize, PolicyNever> = SelfRefCol::from_pinned_vec(pinned);
+    assert_eq!(col.len(), 3);
+    assert_eq!(col.nodes().len(), 4);
+    assert!(col.ends().is_empty());
+}
+
+#[test]
+fn contains_idx() {
+    let mut col: Col<usize, PolicyOnThreshold<1, usize>> = SelfRefCol::new();
+    let idx: Vec<_> = (0..4).map(|x| push(&mut col, x)).collect();
+    assert!(idx.iter().all(|x| col.contains_idx(x)));
+
+    let ptr = col.try_get_ptr(&idx[1]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert!(!col.contains_idx(&idx[1]));
+    assert!(col.contains_idx(&idx[0]));
+
+    let ptr = col.try_get_ptr(&idx[2]).unwrap();
+    col.close_and_reclaim(&ptr);
+    let ptr = col.try_get_ptr(&idx[0]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert_eq!(col.nodes().len(), 1);
+    assert!(idx.iter().all(|x| !col.contains_idx(x)));
+
+    let other: Col<usize, PolicyOnThreshold<1, usize>> = SelfRefCol::new();
+    assert!(!other.contains_idx(&idx[0]));
+}
+
+#[test]
+fn refill() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx = push(&mut col, 42);
+    let state = col.memory_state();
+
+    col.refill(0..1000);
+    assert_eq!(col.len(), 1000);
+    assert_ne!(col.memory_state(), state);
+    assert!(!col.contains_idx(&idx));
+    let capacity = col.utilization().capacity;
+
+    for frame in 1..3 {
+        let state = col.memory_state();
+        col.refill((0..1000).map(|x| x * frame));
+        assert_eq!(col.len(), 1000);
+        assert_ne!(col.memory_state(), state);
+        assert_eq!(col.utilization().capacity, capacity);
+        assert_eq!(col.nodes()[999].data(), Some(&(999 * frame)));
+    }
+}
+
+#[test]
+fn index_by_node_idx() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..3).map(|x| push(&mut col, x.to_string())).collect();
+
+    assert_eq!(&col[&idx[1]], "1");
+
+    col[&idx[2]].push('2');
+    assert_eq!(&col[&idx[2]], "22");
+}
+
+#[test]
+#[should_panic(expected = "RemovedNode")]
+fn index_by_removed_node_idx_panics() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..3).map(|x| push(&mut col, x.to_string())).collect();
+
+    let ptr = col.try_get_ptr(&idx[1]).unwrap();
+    col.close_and_reclaim(&ptr);
+
+    let _ = &col[&idx[1]];
+}
+
+#[test]
+fn active_positions() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..6).map(|x| push(&mut col, x)).collect();
+    assert_eq!(col.active_positions().count(), col.len());
+
+    for i in [0, 3, 4] {
+        let ptr = col.try_get_ptr(&idx[i]).unwrap();
+        col.close_and_reclaim(&ptr);
+    }
+    assert_eq!(col.active_positions().count(), col.len());
+    assert_eq!(col.active_positions().collect::<Vec<_>>(), vec![1, 2, 5]);
+
+    for pos in col.active_positions() {
+        let ptr = col.node_ptr_at_pos(pos);
+        assert!(col.node(&ptr).is_active());
+    }
+}
+
+#[test]
+fn idx_at_position() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x)).collect();
+
+    let third = col.idx_at_position(2).unwrap();
+    assert_eq!(third, idx[2]);
+    assert_eq!(col[&third], 2);
+    col[&third] = 20;
+    assert_eq!(col.node_from_idx(&idx[2]).unwrap().data(), Some(&20));
+
+    let ptr = col.try_get_ptr(&idx[1]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert_eq!(col.idx_at_position(1), None);
+    assert_eq!(col.idx_at_position(5), None);
+}
+
+#[test]
+fn take() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x.to_string())).collect();
+    let ptr = col.try_get_ptr(&idx[2]).unwrap();
+    col.close_and_reclaim(&ptr);
+    let state = col.memory_state();
+
+    let data = col.take();
+    assert_eq!(data, ["0", "1", "3", "4"]);
+    assert!(col.is_empty());
+    assert_eq!(col.nodes().len(), 0);
+    assert_ne!(col.memory_state(), state);
+    assert!(idx.iter().all(|x| !col.contains_idx(x)));
+
+    let idx = push(&mut col, "new".to_string());
+    assert_eq!(col[&idx], "new");
+    assert_eq!(col.len(), 1);
+}
+
+#[derive(Clone, Default)]
+struct TaggedPolicy {
+    tag: usize,
+}
+
+impl<T> MemoryPolicy<Pool<T>> for TaggedPolicy {
+    fn reclaim_closed_nodes<P>(
+        &self,
+        col: &mut CoreCol<Pool<T>, P>,
+        closed_node_ptr: &NodePtr<Pool<T>>,
+    ) -> bool
+    where
+        P: PinnedVec<Node<Pool<T>>>,
+    {
+        MemoryPolicy::<Pool<T>>::reclaim_closed_nodes(&PolicyNever::default(), col, closed_node_ptr)
+    }
+}
+
+#[test]
+fn with_capacity_and_policy() {
+    let mut col: SelfRefCol<Pool<usize>, TaggedPolicy, FixedVec<_>> =
+        SelfRefCol::with_capacity_and_policy(100, TaggedPolicy { tag: 42 });
+
+    assert!(col.is_empty());
+    assert_eq!(col.memory().tag, 42);
+    assert_eq!(col.utilization().capacity, 100);
+
+    for i in 0..100 {
+        col.push(i);
+    }
+    assert_eq!(col.len(), 100);
+    assert_eq!(col.utilization().capacity, 100);
+
+    let col: SelfRefCol<Pool<usize>, TaggedPolicy, FixedVec<_>> = SelfRefCol::with_capacity(10);
+    assert_eq!(col.memory().tag, 0);
+    assert_eq!(col.utilization().capacity, 10);
+}
+
+#[test]
+fn with_capacity_and_policy_split_vec() {
+    let mut col: Col<usize, TaggedPolicy> =
+        SelfRefCol::with_capacity_and_policy(100, TaggedPolicy { tag: 42 });
+
+    assert!(col.is_empty());
+    assert_eq!(col.memory().tag, 42);
+    assert_eq!(col.utilization().capacity, 100);
+
+    for i in 0..101 {
+        col.push(i);
+    }
+    assert_eq!(col.len(), 101);
+    assert_eq!(col.nodes().fragments()[0].len(), 100);
+    assert_eq!(col.nodes().fragments().len(), 2);
+
+    let col: Col<usize, TaggedPolicy> = SelfRefCol::with_capacity(10);
+    assert_eq!(col.memory().tag, 0);
+    assert_eq!(col.utilization().capacity, 10);
+}
+
+#[test]
+fn invalidate_all_indices() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..4).map(|x| push(&mut col, x)).collect();
+    let ptr = col.try_get_ptr(&idx[2]).unwrap();
+    assert!(idx.iter().all(|x| col.contains_idx(x)));
+
+    col.invalidate_all_indices();
+
+    for x in &idx {
+        assert_eq!(col.try_get_ptr(x), Err(NodeIdxError::ReorganizedCollection));
+    }
+    assert_eq!(col.len(), 4);
+    assert_eq!(col.position_of(&ptr), Some(2));
+    assert_eq!(col.node(&ptr).data(), Some(&2));
+
+    let new_idx = col.idx_at_position(2).unwrap();
+    assert_eq!(col[&new_idx], 2);
+}
+
+#[test]
+fn remove_indices() {
+    let mut col: Col<usize, PolicyOnThreshold<1, usize>> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x)).collect();
+    let state = col.memory_state();
+
+    let removed = col.remove_indices(&[
+        idx[0].clone(),
+        idx[3].clone(),
+        idx[1].clone(),
+        idx[3].clone(),
+    ]);
+    assert_eq!(removed, [Some(0), Some(3), Some(1), None]);
+
+    assert_eq!(col.len(), 2);
+    assert_eq!(col.stats().num_reclaims, 1);
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(col.nodes().len(), 2);
+
+    let removed = col.remove_indices(&[idx[2].clone(), idx[4].clone()]);
+    assert_eq!(removed, [None, None]);
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn into_iter_ref() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5).map(|x| push(&mut col, x)).collect();
+    col.remove_indices(&[idx[1].clone()]);
+
+    let mut sum = 0;
+    for x in &col {
+        sum += x;
+    }
+    assert_eq!(sum, 2 + 3 + 4);
+    assert_eq!((&col).into_iter().count(), 4);
+}
+
+#[test]
+fn close_and_reclaim_signaling() {
+    let mut col: Col<usize, PolicyOnThreshold<2, usize>> = SelfRefCol::new();
+    let idx: Vec<_> = (0..8).map(|x| push(&mut col, x)).collect();
+
+    let mut signaled = false;
+    for (i, x) in idx.iter().enumerate() {
+        let state = col.memory_state();
+        let ptr = col.try_get_ptr(x).expect("no node is moved yet");
+        let (data, nodes_moved) = col.close_and_reclaim_signaling(&ptr);
+        assert_eq!(data, i);
+        assert_eq!(nodes_moved, col.memory_state() != state);
+        if nodes_moved {
+            signaled = true;
+            break;
+        }
+    }
+
+    assert!(signaled);
+    assert!(col.try_get_ptr(&idx[7]).is_err());
+}
+
+#[test]
+fn as_ref_and_as_mut_core_col() {
+    type Core<T> = CoreCol<Pool<T>, SplitVec<Node<Pool<T>>, Recursive>>;
+
+    fn sum(col: impl AsRef<Core<usize>>) -> usize {
+        let col = col.as_ref();
+        col.active_positions()
+            .filter_map(|x| col.nodes()[x].data())
+            .sum()
+    }
+
+    fn push_ten(mut col: impl AsMut<Core<usize>>) {
+        col.as_mut().push(10);
+    }
+
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    for x in 0..4 {
+        push(&mut col, x);
+    }
+    assert_eq!(sum(&col), 6);
+
+    push_ten(&mut col);
+    assert_eq!(col.len(), 5);
+    assert_eq!(sum(&col), 16);
+
+    let (mut core, _) = col.into_inner();
+    push_ten(&mut core);
+    assert_eq!(sum(&core), 26);
+}
+
+#[test]
+fn push_bounded_under_capacity() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let idx = col.push_bounded(7, 2).unwrap();
+    assert_eq!(col.node_from_idx(&idx).and_then(|x| x.data()), Some(&7));
+    assert!(col.push_bounded(8, 2).is_ok());
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn push_bounded_over_capacity() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    push(&mut col, 0);
+    push(&mut col, 1);
+    assert_eq!(col.push_bounded(2, 2).map(|_| ()), Err(2));
+    assert_eq!(col.push_bounded(3, 0).map(|_| ()), Err(3));
+    assert_eq!(col.len(), 2);
+}
+
+#[test]
+fn untyped_node_idx() {
+    let mut numbers: Col<usize, PolicyNever> = SelfRefCol::new();
+    let mut other_numbers: Col<usize, PolicyNever> = SelfRefCol::new();
+    let mut words: Col<String, PolicyNever> = SelfRefCol::new();
+
+    let idx = push(&mut numbers, 42);
+    push(&mut other_numbers, 42);
+    let word = push(&mut words, "x".to_string());
+
+    let table = [idx.clone().erase(), word.clone().erase()];
+
+    assert_eq!(table[0].downcast(&numbers), Some(idx));
+    assert_eq!(table[0].downcast(&other_numbers), None);
+    assert_eq!(table[0].downcast(&words), None);
+    assert_eq!(table[1].downcast(&words), Some(word));
+    assert_eq!(table[1].downcast(&numbers), None);
+
+    numbers.clear();
+    assert_eq!(table[0].downcast(&numbers), None);
+}
+
+#[test]
+fn get_or_insert_with() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    push(&mut col, "a".to_string());
+
+    let b = col.get_or_insert_with(|x| x == "b", || "b".to_string());
+    assert_eq!(col.len(), 2);
+
+    let b_again = col.get_or_insert_with(|x| x == "b", || unreachable!());
+    assert_eq!(b, b_again);
+    assert_eq!(col.len(), 2);
+
+    let a = col.get_or_insert_with(|x| x == "a", || unreachable!());
+    assert_eq!(
+        col.node_from_idx(&a).and_then(|x| x.data()),
+        Some(&"a".to_string())
+    );
+}
+
+struct Tree<T>(PhantomData<T>);
+
+impl<T> Variant for Tree<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsSingle<Self>;
+
+    type Next = RefsVec<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+type TreeCol<T> = SelfRefCol<Tree<T>, MemoryReclaimNever, SplitVec<Node<Tree<T>>, Recursive>>;
+
+#[test]
+fn clone_reachable_from() {
+    // 0 -> {1, 2}, 1 -> {3, 4}, 2 -> {5}
+    let parents = [None, Some(0), Some(0), Some(1), Some(1), Some(2)];
+    let mut col = TreeCol::<usize>::new();
+    let ptrs: Vec<_> = (0..parents.len()).map(|x| col.push(x)).collect();
+    for (child, parent) in parents.iter().enumerate() {
+        match parent {
+            Some(p) => {
+                col.node_mut(&ptrs[*p]).next_mut().push(ptrs[child].clone());
+                col.node_mut(&ptrs[child]).prev_mut().set_some(&ptrs[*p]);
+            }
+            None => col.ends_mut().set_some(&ptrs[child]),
+        }
+    }
+
+    let idx = col.idx_at_position(1).unwrap();
+    let sub = col.clone_reachable_from(&idx).unwrap();
+    assert_eq!(sub.len(), 3);
+    assert!(sub.ends().get().is_none());
+    assert_eq!(sub.check(), Ok(()));
+
+    let root = sub.node_ptr_at_pos(0);
+    assert_eq!(sub.node(&root).data(), Some(&1));
+    assert!(sub.node(&root).prev().get().is_none());
+    let children: Vec<_> = sub
+        .next_ptrs_of(&root)
+        .iter()
+        .map(|x| *sub.node(x).data().unwrap())
+        .collect();
+    assert_eq!(children, [3, 4]);
+    for child in sub.next_ptrs_of(&root) {
+        assert_eq!(sub.node(&child).prev().get(), Some(root.clone()));
+        assert!(sub.node(&child).next().is_empty());
+    }
+
+    let whole = col
+        .clone_reachable_from(&col.idx_at_position(0).unwrap())
+        .unwrap();
+    assert_eq!(whole.len(), 6);
+    assert_eq!(whole.ends().get(), Some(whole.node_ptr_at_pos(0)));
+
+    col.clear();
+    assert!(col.clone_reachable_from(&idx).is_none());
+}
+
+#[test]
+fn compact_excluding() {
+    let mut col = TreeCol::<usize>::new();
+    let ptrs: Vec<_> = (0..8).map(|x| col.push(x)).collect();
+    col.ends_mut().set_some(&ptrs[0]);
+    for child in &ptrs[1..] {
+        col.node_mut(&ptrs[0]).next_mut().push(child.clone());
+        col.node_mut(child).prev_mut().set_some(&ptrs[0]);
+    }
+
+    for i in [1, 2, 4] {
+        col.node_mut(&ptrs[0]).next_mut().swap_remove_ptr(&ptrs[i]);
+        col.close(&ptrs[i]);
+    }
+
+    let state = col.memory_state();
+    let idx = NodeIdx::new(state, &ptrs[6]);
+    let pinned = [ptrs[0].clone(), ptrs[6].clone()];
+    assert!(col.compact_excluding(&pinned));
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(
+        col.try_get_ptr(&idx),
+        Err(NodeIdxError::ReorganizedCollection)
+    );
+    assert_eq!(col.stats().num_reclaims, 1);
+
+    assert_eq!(col.nodes().len(), 7);
+    assert_eq!(col.len(), 5);
+    assert_eq!(col.position_of(&ptrs[0]), Some(0));
+    assert_eq!(col.position_of(&ptrs[6]), Some(6));
+    assert_eq!(col.node(&ptrs[6]).data(), Some(&6));
+
+    let root = col.ends().get().expect("has a root");
+    assert_eq!(root, ptrs[0]);
+
+    let mut data: Vec<_> = col
+        .next_ptrs_of(&root)
+        .iter()
+        .map(|x| {
+            assert_eq!(col.node(x).prev().get(), Some(root.clone()));
+            *col.node(x).data().expect("is active")
+        })
+        .collect();
+    data.sort();
+    assert_eq!(data, [3, 5, 6, 7]);
+
+    let state = col.memory_state();
+    assert!(!col.compact_excluding(&pinned));
+    assert_eq!(col.memory_state(), state);
+}
+
+#[test]
+fn self_ref_col_std_alias() {
+    let mut col = SelfRefColStd::<Pool<usize>>::new();
+    col.push(1);
+    col.push(2);
+    let col: Col<usize, PolicyNever> = col;
+    assert_eq!(col.len(), 2);
+
+    let col: SelfRefColStd<Pool<usize>, PolicyOnThreshold<2, usize>> = SelfRefCol::new();
+    let nodes: &DefaultPinnedVec<Pool<usize>> = col.nodes();
+    assert!(nodes.is_empty());
+}
+
+#[test]
+fn from_threshold_to_never_keeps_indices_valid() {
+    let mut col: Col<usize, PolicyOnThreshold<2, usize>> = SelfRefCol::new();
+    let idx: Vec<_> = (0..8).map(|x| push(&mut col, x)).collect();
+    let ptr = col.try_get_ptr(&idx[0]).unwrap();
+    col.close_and_reclaim(&ptr);
+    assert_eq!(col.stats().num_reclaims, 0);
+
+    let mut col: Col<usize, PolicyNever> = col.into();
+    for i in &idx[1..] {
+        assert!(col.contains_idx(i));
+    }
+
+    for i in &idx[1..7] {
+        let ptr = col.try_get_ptr(i).unwrap();
+        col.close_and_reclaim(&ptr);
+    }
+    assert!(col.contains_idx(&idx[7]));
+    assert_eq!(col.node_from_idx(&idx[7]).and_then(|x| x.data()), Some(&7));
+}
+
+#[test]
+fn iter_level_order() {
+    let mut col = TreeCol::<usize>::new();
+    assert_eq!(col.iter_level_order().count(), 0);
+
+    // 0 -> {1, 2}, 1 -> {3, 4}, 2 -> {5, 6}; pushed in depth-first order
+    let ptrs: Vec<_> = [0, 1, 3, 4, 2, 5, 6].map(|x| col.push(x)).into();
+    let [n0, n1, n3, n4, n2, n5, n6] = [0, 1, 2, 3, 4, 5, 6].map(|i| ptrs[i].clone());
+    col.ends_mut().set_some(&n0);
+    for (parent, children) in [
+        (n0, [n1.clone(), n2.clone()]),
+        (n1, [n3, n4]),
+        (n2, [n5, n6]),
+    ] {
+        for child in children {
+            col.node_mut(&parent).next_mut().push(child.clone());
+            col.node_mut(&child).prev_mut().set_some(&parent);
+        }
+    }
+
+    let order: Vec<_> = col.iter_level_order().map(|x| *x.data().unwrap()).collect();
+    assert_eq!(order, [0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn detailed_utilization() {
+    let mut col = TreeCol::<usize>::new();
+    let initial = col.detailed_utilization();
+    assert_eq!(initial.utilization, col.utilization());
+    assert_eq!(initial.refs_heap_bytes, 0);
+    assert_eq!(initial.num_fragments, 1);
+
+    let root = col.push(0);
+    let first_capacity = col.nodes().fragments()[0].capacity();
+    for x in 1..=first_capacity {
+        let child = col.push(x);
+        col.node_mut(&root).next_mut().push(child);
+    }
+
+    let detailed = col.detailed_utilization();
+    assert_eq!(detailed.utilization, col.utilization());
+    assert_eq!(detailed.utilization.num_active_nodes, first_capacity + 1);
+    assert!(detailed.num_fragments > initial.num_fragments);
+    assert!(detailed.refs_heap_bytes >= first_capacity * core::mem::size_of::<usize>());
+}
+
+#[test]
+fn try_as_contiguous_nodes() {
+    let mut col: SelfRefCol<Pool<usize>, PolicyNever, FixedVec<_>> = SelfRefCol::with_capacity(10);
+    assert_eq!(col.try_as_contiguous_nodes().map(|x| x.len()), Some(0));
+
+    let ptrs: Vec<_> = (0..10).map(|x| col.push(x)).collect();
+    let data: Vec<_> = col
+        .try_as_contiguous_nodes()
+        .unwrap()
+        .iter()
+        .map(|x| *x.data().unwrap())
+        .collect();
+    assert_eq!(data, (0..10).collect::<Vec<_>>());
+
+    col.close(&ptrs[3]);
+    assert!(col.try_as_contiguous_nodes().is_none());
+
+    let mut col: SelfRefCol<Pool<usize>, PolicyNever, SplitVec<_, Recursive>> = SelfRefCol::new();
+    col.push(0);
+    assert_eq!(col.try_as_contiguous_nodes().map(|x| x.len()), Some(1));
+
+    while col.nodes().fragments().len() == 1 {
+        let x = col.len();
+        col.push(x);
+    }
+    assert!(col.try_as_contiguous_nodes().is_none());
+}
+
+#[test]
+fn generations_elapsed_monotonic() {
+    let mut col: Col<usize, PolicyOnThreshold<1, usize>> = SelfRefCol::new();
+    assert_eq!(col.generations_elapsed(), 0);
+
+    let mut generations = vec![col.generations_elapsed()];
+    let mut states = vec![col.memory_state()];
+    for round in 0..1000 {
+        // closing the first two nodes moves the third one to the front
+        let idx: Vec<_> = (0..3).map(|x| push(&mut col, round * 3 + x)).collect();
+        let ptrs: Vec<_> = idx.iter().map(|x| col.try_get_ptr(x).unwrap()).collect();
+        col.close_and_reclaim(&ptrs[0]);
+        col.close_and_reclaim(&ptrs[1]);
+        let ptr = col.node_ptr_at_pos(0);
+        col.close_and_reclaim(&ptr);
+        assert!(col.is_empty());
+        generations.push(col.generations_elapsed());
+        states.push(col.memory_state());
+    }
+
+    assert!(generations.windows(2).all(|x| x[0] < x[1]));
+    assert_eq!(generations[generations.len() - 1], 1000);
+    for (i, state) in states.iter().enumerate() {
+        assert!(states[(i + 1)..].iter().all(|x| x != state));
+    }
+
+    let before = col.generations_elapsed();
+    for _ in 0..10_000 {
+        col.update_state(true);
+    }
+    col.update_state(false);
+    assert_eq!(col.generations_elapsed(), before + 10_000);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn filter_valid_indices() {
+    use std::collections::HashMap;
+
+    let mut col: Col<usize, PolicyOnThreshold<2, usize>> = SelfRefCol::new();
+    let mut map: HashMap<usize, NodeIdx<Pool<usize>>> =
+        (0..8).map(|x| (x, push(&mut col, x))).collect();
+
+    col.filter_valid_indices(&mut map);
+    assert_eq!(map.len(), 8);
+
+    let ptr = col.try_get_ptr(&map[&3]).unwrap();
+    col.close_and_reclaim(&ptr);
+    col.filter_valid_indices(&mut map);
+    assert_eq!(map.len(), 7);
+    assert!(!map.contains_key(&3));
+
+    let state = col.memory_state();
+    let ptrs: Vec<_> = [0, 1, 2].map(|x| col.try_get_ptr(&map[&x]).unwrap()).into();
+    col.close(&ptrs[0]);
+    col.close(&ptrs[1]);
+    col.close_and_reclaim(&ptrs[2]);
+    assert_ne!(col.memory_state(), state);
+
+    col.filter_valid_indices(&mut map);
+    assert!(map.is_empty());
+    assert_eq!(col.len(), 4);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn compact_and_remap() {
+    use std::collections::HashMap;
+
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let mut handles: HashMap<usize, NodeIdx<Pool<String>>> = (0..10)
+        .map(|x| (x, push(&mut col, x.to_string())))
+        .collect();
+
+    for x in [0, 3, 4, 8] {
+        let ptr = col.try_get_ptr(&handles[&x]).unwrap();
+        col.close_and_reclaim(&ptr);
+    }
+    assert_eq!(col.nodes().len(), 10);
+
+    let state = col.memory_state();
+    col.compact_and_remap(&mut handles);
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(col.nodes().len(), 6);
+
+    let mut keys: Vec<_> = handles.keys().copied().collect();
+    keys.sort();
+    assert_eq!(keys, [1, 2, 5, 6, 7, 9]);
+    for (key, idx) in &handles {
+        let ptr = col.try_get_ptr(idx).unwrap();
+        assert_eq!(col.node(&ptr).data(), Some(&key.to_string()));
+    }
+
+    // nothing to reclaim
+    let state = col.memory_state();
+    col.compact_and_remap(&mut handles);
+    assert_eq!(col.memory_state(), state);
+    assert_eq!(handles.len(), 6);
+}
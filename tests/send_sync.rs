@@ -0,0 +1,101 @@
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+struct Singly<T>(PhantomData<T>);
+
+impl<T> Variant for Singly<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+struct Doubly<T>(PhantomData<T>);
+
+impl<T> Variant for Doubly<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsSingle<Self>;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsArray<2, Self>;
+}
+
+type Col<T> = SelfRefCol<Singly<T>, MemoryReclaimNever, SplitVec<Node<Singly<T>>, Recursive>>;
+
+fn assert_send<T: Send>() {}
+
+fn assert_sync<T: Sync>() {}
+
+// `some_item` is ambiguous, and hence, fails to compile if `T` implements both `AmbiguousIfSend<()>`
+// and `AmbiguousIfSend<IsSend>`; i.e., if `T` is `Send`
+trait AmbiguousIfSend<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSend<()> for T {}
+struct IsSend;
+impl<T: ?Sized + Send> AmbiguousIfSend<IsSend> for T {}
+
+trait AmbiguousIfSync<A> {
+    fn some_item() {}
+}
+impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+struct IsSync;
+impl<T: ?Sized + Sync> AmbiguousIfSync<IsSync> for T {}
+
+#[test]
+fn col_is_send_sync_if_item_is() {
+    assert_send::<Col<u32>>();
+    assert_sync::<Col<u32>>();
+    assert_send::<NodeIdx<Singly<u32>>>();
+    assert_sync::<NodeIdx<Singly<u32>>>();
+    assert_send::<SelfRefColStd<Doubly<String>>>();
+    assert_sync::<SelfRefColStd<Doubly<String>>>();
+}
+
+#[test]
+fn col_is_not_send_sync_if_item_is_not() {
+    <Col<Rc<u32>> as AmbiguousIfSend<_>>::some_item();
+    <Col<Rc<u32>> as AmbiguousIfSync<_>>::some_item();
+    <NodeIdx<Singly<Rc<u32>>> as AmbiguousIfSend<_>>::some_item();
+    <NodeIdx<Singly<Rc<u32>>> as AmbiguousIfSync<_>>::some_item();
+}
+
+#[test]
+fn send_node_idx() {
+    let mut col: Col<u32> = SelfRefCol::new();
+    let idx: Vec<_> = (0..4)
+        .map(|x| {
+            let ptr = col.push(x);
+            NodeIdx::new(col.memory_state(), &ptr)
+        })
+        .collect();
+
+    let col = &col;
+    let sum: u32 = std::thread::scope(|s| {
+        let handles: Vec<_> = idx
+            .iter()
+            .map(|idx| s.spawn(move || col[idx] * 10))
+            .collect();
+        handles
+            .into_iter()
+            .map(|x| x.join().expect("no panic"))
+            .sum()
+    });
+    assert_eq!(sum, 60);
+
+    let moved = std::thread::spawn(move || idx.len())
+        .join()
+        .expect("no panic");
+    assert_eq!(moved, 4);
+}
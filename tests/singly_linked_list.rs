@@ -1,4 +1,4 @@
-use orx_pinned_vec::PinnedVec;
+use orx_pinned_vec::{Collection, PinnedVec};
 use orx_selfref_col::*;
 use orx_split_vec::{Recursive, SplitVec};
 use std::marker::PhantomData;
@@ -73,6 +73,8 @@ where
 impl<T> Variant for Singly<T> {
     type Item = T;
 
+    type Storage = NodeDataLazyClose<T>;
+
     type Prev = RefsNone;
 
     type Next = RefsSingle<Self>;
@@ -100,7 +102,7 @@ where
     let mut vec = vec![];
 
     if !col.is_empty() {
-        let front = front(&col);
+        let front = front(col);
         vec.push(front.data().unwrap().clone());
 
         let mut current = front;
@@ -172,13 +174,7 @@ fn reorganize_old(col: &mut Col<String, PolicyNever>) {
 }
 
 fn next_occupied_old(col: &Col<String, PolicyNever>, start_position: usize) -> Option<usize> {
-    for i in start_position..col.nodes().len() {
-        if col.nodes()[i].is_active() {
-            return Some(i);
-        }
-    }
-
-    None
+    (start_position..col.nodes().len()).find(|&i| col.nodes()[i].is_active())
 }
 
 #[test]
@@ -749,3 +745,356 @@ fn node_ref_validation_threshold_reclaim() {
         assert!(all_invalid);
     }
 }
+
+#[test]
+fn front_data() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(col.front(), None);
+
+    push_front(&mut col, 0.to_string());
+    assert_eq!(col.front(), Some(&0.to_string()));
+
+    push_front(&mut col, 1.to_string());
+    assert_eq!(col.front(), Some(&1.to_string()));
+
+    pop_front(&mut col);
+    pop_front(&mut col);
+    assert_eq!(col.front(), None);
+}
+
+#[test]
+fn rotate_front() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    let idx: Vec<_> = (0..5)
+        .rev()
+        .map(|x| push_front(&mut col, x.to_string()))
+        .collect();
+    let state = col.memory_state();
+    assert_eq!(forward(&col), to_str(&[0, 1, 2, 3, 4]));
+
+    col.rotate_front(2);
+    assert_eq!(forward(&col), to_str(&[2, 3, 4, 0, 1]));
+
+    col.rotate_front(7);
+    assert_eq!(forward(&col), to_str(&[4, 0, 1, 2, 3]));
+
+    col.rotate_front(0);
+    col.rotate_front(5);
+    assert_eq!(forward(&col), to_str(&[4, 0, 1, 2, 3]));
+
+    assert_eq!(col.memory_state(), state);
+    for (i, idx) in idx.iter().enumerate() {
+        let value = (4 - i).to_string();
+        assert_eq!(col.node_from_idx(idx).unwrap().data(), Some(&value));
+    }
+}
+
+#[test]
+fn to_vec() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert!(col.to_vec().is_empty());
+
+    for x in (0..6).rev() {
+        push_front(&mut col, x.to_string());
+    }
+    assert_eq!(col.to_vec(), forward(&col));
+
+    pop_front(&mut col);
+    col.rotate_front(2);
+    assert_eq!(col.to_vec(), to_str(&[3, 4, 5, 1, 2]));
+    assert_eq!(col.to_vec(), forward(&col));
+}
+
+#[test]
+fn extract_if() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in (0..10).rev() {
+        push_front(&mut col, x.to_string());
+    }
+
+    let odds = col.extract_if(|x| x.parse::<usize>().expect("is a number") % 2 == 1);
+    assert_eq!(odds, to_str(&[1, 3, 5, 7, 9]));
+    assert_eq!(forward(&col), to_str(&[0, 2, 4, 6, 8]));
+    assert_eq!(col.len(), 5);
+
+    let front = col.extract_if(|x| x == "0");
+    assert_eq!(front, to_str(&[0]));
+    assert_eq!(forward(&col), to_str(&[2, 4, 6, 8]));
+
+    assert!(col.extract_if(|x| x == "100").is_empty());
+    assert_eq!(forward(&col), to_str(&[2, 4, 6, 8]));
+}
+
+#[test]
+fn dedup_by_key() {
+    let mut col: Col<String, PolicyOnThreshold<1, String>> = SelfRefCol::new();
+    for x in [3, 3, 3, 2, 1, 1] {
+        push_front(&mut col, x.to_string());
+    }
+
+    col.dedup_by_key(|x| x.clone());
+    assert_eq!(forward(&col), to_str(&[1, 2, 3]));
+    assert_eq!(col.len(), 3);
+
+    col.dedup_by_key(|x| x.len());
+    assert_eq!(forward(&col), to_str(&[1]));
+}
+
+#[test]
+fn merge_sorted_by() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [5, 3, 1] {
+        push_front(&mut col, x.to_string());
+    }
+
+    let mut other: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [6, 4, 2] {
+        push_front(&mut other, x.to_string());
+    }
+    let (other, _) = other.into_inner();
+
+    let state = col.memory_state();
+    col.merge_sorted_by(other, |a, b| a.cmp(b));
+    assert_eq!(forward(&col), to_str(&[1, 2, 3, 4, 5, 6]));
+    assert_eq!(col.len(), 6);
+    assert_eq!(col.memory_state(), state);
+
+    let mut other: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [8, 7, 0] {
+        push_front(&mut other, x.to_string());
+    }
+    let (other, _) = other.into_inner();
+    col.merge_sorted_by(other, |a, b| a.cmp(b));
+    assert_eq!(forward(&col), to_str(&[0, 1, 2, 3, 4, 5, 6, 7, 8]));
+}
+
+#[test]
+fn end_node() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert!(col.end_node(0).is_none());
+
+    push_front(&mut col, 1.to_string());
+    push_front(&mut col, 0.to_string());
+
+    assert_eq!(col.end_node(0).and_then(|x| x.data()), Some(&0.to_string()));
+    assert!(col.end_node(1).is_none());
+}
+
+#[test]
+fn recompute_ends_singly() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert!(col.recompute_ends_singly());
+    assert!(col.ends().get().is_none());
+
+    for x in [3, 2, 1, 0] {
+        push_front(&mut col, x.to_string());
+    }
+
+    col.ends_mut().clear();
+    assert!(col.recompute_ends_singly());
+    assert_eq!(forward(&col), to_str(&[0, 1, 2, 3]));
+
+    let free = col.push(4.to_string());
+    col.ends_mut().clear();
+    assert!(!col.recompute_ends_singly());
+    assert!(col.ends().get().is_none());
+
+    let back = col.node_ptr_at_pos(0);
+    col.node_mut(&back).next_mut().set_some(&free);
+    assert!(col.recompute_ends_singly());
+    assert_eq!(forward(&col), to_str(&[0, 1, 2, 3, 4]));
+}
+
+#[test]
+fn cursor_mut() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [3, 2, 1, 0] {
+        push_front(&mut col, x.to_string());
+    }
+
+    let mut cursor = col.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(2.to_string()));
+    assert_eq!(cursor.current(), Some(&3.to_string()));
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&1.to_string()));
+    cursor.insert_after(2.to_string());
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.remove_current(), None);
+    cursor.insert_after("f".to_string());
+    drop(cursor);
+
+    assert_eq!(forward(&col), ["f", "0", "1", "2", "3"]);
+    assert_eq!(col.len(), 5);
+}
+
+#[test]
+fn swap_data_at() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [4, 3, 2, 1, 0] {
+        push_front(&mut col, x.to_string());
+    }
+    // positions hold 4, 3, 2, 1, 0 while the list is 0, 1, 2, 3, 4
+    col.swap_data_at(0, 4);
+    assert_eq!(forward(&col), to_str(&[4, 1, 2, 3, 0]));
+
+    col.swap_data_at(2, 2);
+    assert_eq!(forward(&col), to_str(&[4, 1, 2, 3, 0]));
+}
+
+#[test]
+#[should_panic]
+fn swap_data_at_closed() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    for x in [1, 0] {
+        push_front(&mut col, x.to_string());
+    }
+    pop_front(&mut col);
+    col.swap_data_at(0, 1);
+}
+
+#[test]
+fn retain_mut_linked() {
+    let mut col: Col<String, PolicyOnThreshold<1, String>> = SelfRefCol::new();
+    for x in (0..6).rev() {
+        push_front(&mut col, x.to_string());
+    }
+
+    col.retain_mut_linked(|x| {
+        let incremented = x.parse::<usize>().unwrap() + 1;
+        *x = incremented.to_string();
+        incremented <= 4
+    });
+    assert_eq!(forward(&col), to_str(&[1, 2, 3, 4]));
+    assert_eq!(col.len(), 4);
+
+    col.retain_mut_linked(|_| false);
+    assert!(col.is_empty());
+    assert!(forward(&col).is_empty());
+}
+
+#[test]
+fn num_ends() {
+    let mut col: Col<String, PolicyNever> = SelfRefCol::new();
+    assert_eq!(col.num_ends(), 0);
+
+    push_front(&mut col, 0.to_string());
+    push_front(&mut col, 1.to_string());
+    assert_eq!(col.num_ends(), 1);
+
+    pop_front(&mut col);
+    pop_front(&mut col);
+    assert_eq!(col.num_ends(), 0);
+}
+
+#[test]
+fn swap_remove_active() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+    col.node_mut(&ptrs[0]).next_mut().set_some(&ptrs[4]);
+    let state = col.memory_state();
+    let idx = NodeIdx::new(state, &ptrs[2]);
+
+    let (removed, moved_from) = col.swap_remove_active(2);
+    assert_eq!(removed, 2);
+    assert_eq!(moved_from, Some(4));
+    assert_eq!(col.len(), 4);
+    assert_eq!(col.nodes().len(), 4);
+
+    // the index of the removed node does not resolve to the moved node, and the references to the
+    // moved node are repaired by the caller
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(
+        col.try_get_ptr(&idx),
+        Err(NodeIdxError::ReorganizedCollection)
+    );
+    let new_ptr = col.node_ptr_at_pos(2);
+    col.node_mut(&ptrs[0]).next_mut().set_some(&new_ptr);
+
+    let next = col.node(&ptrs[0]).next().get().unwrap();
+    assert_eq!(col.node(&next).data(), Some(&4));
+
+    let data: Vec<_> = (0..4).map(|i| *col.nodes()[i].data().unwrap()).collect();
+    assert_eq!(data, [0, 1, 4, 3]);
+}
+
+#[test]
+fn user_moves_are_not_counted_as_reclaims() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+
+    col.swap_remove_active(1);
+    col.close(&ptrs[0]);
+    assert_eq!(col.try_move_node(0, 2), Ok(()));
+    assert_eq!(col.stats().num_nodes_moved_total, 0);
+    assert_eq!(col.stats().num_reclaims, 0);
+
+    let ptr = col.node_ptr_at_pos(1);
+    col.close(&ptr);
+    assert!(col.compact_excluding(&[]));
+    assert_eq!(col.stats().num_nodes_moved_total, 1);
+    assert_eq!(col.stats().num_reclaims, 1);
+}
+
+#[test]
+fn swap_remove_active_last() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+    col.close(&ptrs[4]);
+    let idx = NodeIdx::new(col.memory_state(), &ptrs[3]);
+
+    let (removed, moved_from) = col.swap_remove_active(3);
+    assert_eq!(removed, 3);
+    assert_eq!(moved_from, None);
+    assert_eq!(col.len(), 3);
+    assert_eq!(col.nodes().len(), 3);
+
+    // the position of the removed node is reused, the stale index does not resolve to the new node
+    col.push(42);
+    assert_eq!(
+        col.try_get_ptr(&idx),
+        Err(NodeIdxError::ReorganizedCollection)
+    );
+}
+
+#[test]
+fn swap_remove_by_ptr() {
+    let mut col: Col<usize, PolicyNever> = SelfRefCol::new();
+    let ptrs: Vec<_> = (0..5).map(|x| col.push(x)).collect();
+    col.node_mut(&ptrs[0]).next_mut().set_some(&ptrs[4]);
+    let state = col.memory_state();
+    let idx = NodeIdx::new(state, &ptrs[2]);
+
+    let (removed, moved_from) = col.swap_remove(&ptrs[2]).unwrap();
+    assert_eq!(removed, 2);
+    assert_eq!(moved_from, Some(ptrs[4].clone()));
+    assert_eq!(col.len(), 4);
+    assert_eq!(col.nodes().len(), 4);
+
+    // the index of the removed node does not resolve to the moved node
+    assert_ne!(col.memory_state(), state);
+    assert_eq!(
+        col.try_get_ptr(&idx),
+        Err(NodeIdxError::ReorganizedCollection)
+    );
+
+    // repair the reference to the relocated node
+    col.remap_reference(&ptrs[4], &ptrs[2]);
+    let next = col.node(&ptrs[0]).next().get().unwrap();
+    assert_eq!(next, ptrs[2]);
+    assert_eq!(col.node(&next).data(), Some(&4));
+
+    // removing the last node moves nothing
+    assert_eq!(col.swap_remove(&ptrs[3]), Some((3, None)));
+    assert_eq!(col.nodes().len(), 3);
+
+    // nothing is removed, the memory state does not change
+    col.close(&ptrs[1]);
+    let state = col.memory_state();
+    assert_eq!(col.swap_remove(&ptrs[1]), None);
+    assert_eq!(col.swap_remove(&ptrs[4]), None);
+    assert_eq!(col.memory_state(), state);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "proptest")]
+
+use orx_selfref_col::*;
+use orx_split_vec::{Recursive, SplitVec};
+use proptest::prelude::*;
+use std::marker::PhantomData;
+
+struct Singly<T>(PhantomData<T>);
+
+impl<T> Variant for Singly<T> {
+    type Item = T;
+
+    type Storage = NodeDataLazyClose<T>;
+
+    type Prev = RefsNone;
+
+    type Next = RefsSingle<Self>;
+
+    type Ends = RefsSingle<Self>;
+}
+
+type Col<T, M> = SelfRefCol<Singly<T>, M, SplitVec<Node<Singly<T>>, Recursive>>;
+
+fn reverse<T, M>(col: &mut Col<T, M>)
+where
+    M: MemoryPolicy<Singly<T>>,
+{
+    let mut prev = None;
+    let mut current = col.ends().get();
+    while let Some(ptr) = current {
+        current = col.node(&ptr).next().get();
+        col.node_mut(&ptr).next_mut().set(prev);
+        prev = Some(ptr);
+    }
+    col.ends_mut().set(prev);
+}
+
+fn assert_valid<T, M>(col: &Col<T, M>)
+where
+    M: MemoryPolicy<Singly<T>>,
+    T: Clone,
+{
+    assert_eq!(col.active_positions().count(), col.len());
+    assert_eq!(col.to_vec().len(), col.len());
+}
+
+proptest! {
+    #[test]
+    fn reverse_twice_never_reclaim(list in linked_lists::<_, MemoryReclaimNever, _, _>(any::<u32>(), 64)) {
+        let mut col: Col<u32, MemoryReclaimNever> = list.col;
+        assert_valid(&col);
+
+        let values = col.to_vec();
+        reverse(&mut col);
+        reverse(&mut col);
+        prop_assert_eq!(col.to_vec(), values);
+    }
+}